@@ -11,11 +11,30 @@ pub enum AmPm {
 impl AmPm {
     pub fn parse(input: &str) -> NomResult<&str, AmPm> {
         alt((
-            map(alt((tag("am"), tag("AM"))), |_| AmPm::Am),
-            map(alt((tag("pm"), tag("PM"))), |_| AmPm::Pm),
+            map(
+                alt((tag("am"), tag("AM"), tag("a.m."), tag("A.M."))),
+                |_| AmPm::Am,
+            ),
+            map(
+                alt((tag("pm"), tag("PM"), tag("p.m."), tag("P.M."))),
+                |_| AmPm::Pm,
+            ),
         ))
         .parse(input)
     }
+
+    /// Converts a 1-12 hour in this period to a 0-23 hour.
+    pub fn hour24(&self, hour: u8) -> Result<u8, jiff::Error> {
+        match (hour, self) {
+            (12, AmPm::Am) => Ok(0),  // 12 AM -> midnight
+            (12, AmPm::Pm) => Ok(12), // 12 PM -> noon
+            (1..=11, AmPm::Pm) => Ok(hour + 12),
+            (1..=11, AmPm::Am) => Ok(hour), // 1 AM–11 AM -> 1–11
+            _ => Err(jiff::Error::from_args(format_args!(
+                "invalid 12 hour format date"
+            ))),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -29,6 +48,9 @@ mod tests {
 
         let out = AmPm::parse("AM");
         assert!(matches!(out, Ok(("", AmPm::Am))));
+
+        let out = AmPm::parse("a.m.");
+        assert!(matches!(out, Ok(("", AmPm::Am))));
     }
 
     #[test]
@@ -38,5 +60,17 @@ mod tests {
 
         let out = AmPm::parse("PM");
         assert!(matches!(out, Ok(("", AmPm::Pm))));
+
+        let out = AmPm::parse("p.m.");
+        assert!(matches!(out, Ok(("", AmPm::Pm))));
+    }
+
+    #[test]
+    fn hour24() {
+        assert_eq!(AmPm::Am.hour24(12).unwrap(), 0);
+        assert_eq!(AmPm::Am.hour24(8).unwrap(), 8);
+        assert_eq!(AmPm::Pm.hour24(12).unwrap(), 12);
+        assert_eq!(AmPm::Pm.hour24(8).unwrap(), 20);
+        assert!(AmPm::Pm.hour24(13).is_err());
     }
 }
@@ -1,44 +1,44 @@
-use nom::{character::complete::space1, combinator::map, Parser};
+use nom::{character::complete::space0, combinator::map, Parser};
 
-use crate::{parse_hms, AmPm, HmsFormat, NomResult};
+use crate::{oclock, parse_hms, AmPm, EvalContext, HmsFormat, NomResult};
 
 #[derive(Debug)]
 pub struct AmPmTime {
     pub hour: u8, // 1-12
     pub minute: u8,
     pub second: u8,
+    pub nanosecond: u32,
     pub period: AmPm,
 }
 
 impl AmPmTime {
     pub fn parse(input: &str) -> NomResult<&str, AmPmTime> {
         map(
-            (parse_hms(HmsFormat::H12), space1, AmPm::parse),
-            |((hour, minute, second), _, period)| AmPmTime {
+            (parse_hms(HmsFormat::H12), oclock, space0, AmPm::parse),
+            |((hour, minute, second, nanosecond), _, _, period)| AmPmTime {
                 hour,
                 minute,
                 second,
+                nanosecond,
                 period,
             },
         )
         .parse(input)
     }
 
-    pub fn with_zoned(&self, zoned: jiff::Zoned) -> Result<jiff::Zoned, jiff::Error> {
-        let hour24 = match (self.hour, &self.period) {
-            (12, AmPm::Am) => 0,  // 12 AM -> midnight
-            (12, AmPm::Pm) => 12, // 12 PM -> noon
-            (1..=11, AmPm::Pm) => self.hour + 12,
-            (1..=11, AmPm::Am) => self.hour, // 1 AM–11 AM -> 1–11
-            _ => {
-                return Err(jiff::Error::from_args(format_args!(
-                    "invalid 12 hour format date"
-                )))
-            }
-        };
-
-        let t = jiff::civil::time(hour24 as i8, self.minute as i8, self.second as i8, 0);
-        zoned.with().time(t).build()
+    pub fn with_zoned(
+        &self,
+        ctx: &EvalContext,
+        zoned: jiff::Zoned,
+    ) -> Result<jiff::Zoned, jiff::Error> {
+        let hour24 = self.period.hour24(self.hour)?;
+        let t = jiff::civil::time(
+            hour24 as i8,
+            self.minute as i8,
+            self.second as i8,
+            self.nanosecond as i32,
+        );
+        ctx.at_time(&zoned, t)
     }
 }
 
@@ -60,6 +60,7 @@ mod tests {
                     hour: 1,
                     minute: 0,
                     second: 0,
+                    nanosecond: 0,
                     period: AmPm::Pm
                 }
             ))
@@ -77,6 +78,7 @@ mod tests {
                     hour: 1,
                     minute: 30,
                     second: 0,
+                    nanosecond: 0,
                     period: AmPm::Pm
                 }
             ))
@@ -94,12 +96,94 @@ mod tests {
                     hour: 1,
                     minute: 30,
                     second: 24,
+                    nanosecond: 0,
                     period: AmPm::Pm
                 }
             ))
         ));
     }
 
+    #[test]
+    fn parse_fractional_seconds() {
+        let out = AmPmTime::parse("12:30:45.5 pm");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                AmPmTime {
+                    hour: 12,
+                    minute: 30,
+                    second: 45,
+                    nanosecond: 500_000_000,
+                    period: AmPm::Pm
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_compact() {
+        let out = AmPmTime::parse("8pm");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                AmPmTime {
+                    hour: 8,
+                    minute: 0,
+                    second: 0,
+                    nanosecond: 0,
+                    period: AmPm::Pm
+                }
+            ))
+        ));
+
+        let out = AmPmTime::parse("8:30pm");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                AmPmTime {
+                    hour: 8,
+                    minute: 30,
+                    second: 0,
+                    nanosecond: 0,
+                    period: AmPm::Pm
+                }
+            ))
+        ));
+
+        let out = AmPmTime::parse("8 p.m.");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                AmPmTime {
+                    hour: 8,
+                    minute: 0,
+                    second: 0,
+                    nanosecond: 0,
+                    period: AmPm::Pm
+                }
+            ))
+        ));
+
+        let out = AmPmTime::parse("5 o'clock am");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                AmPmTime {
+                    hour: 5,
+                    minute: 0,
+                    second: 0,
+                    nanosecond: 0,
+                    period: AmPm::Am
+                }
+            ))
+        ));
+    }
+
     #[test]
     fn parse_invalid_hour() {
         let input = "13 am";
@@ -1,48 +1,51 @@
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::{digit1, space0},
-    combinator::{map, map_res},
-    Parser,
-};
+use nom::{character::complete::space0, combinator::map_opt, Parser};
 
-use crate::NomResult;
+use crate::{parse_quantity, DurationUnit, NomResult};
 
 #[derive(Debug)]
 pub enum DateDuration {
     Days(u32),
     Weeks(u32),
+    Fortnights(u32),
     Months(u32),
+    Quarters(u32),
     Years(u32),
+    Decades(u32),
+    Centuries(u32),
 }
 
 impl DateDuration {
     pub fn parse(input: &str) -> NomResult<&str, DateDuration> {
-        map(
-            (
-                map_res(digit1, |s: &str| s.parse::<u32>()),
-                space0,
-                alt((
-                    tag("days"),
-                    tag("day"),
-                    tag("weeks"),
-                    tag("week"),
-                    tag("months"),
-                    tag("month"),
-                    tag("years"),
-                    tag("year"),
-                )),
-            ),
+        map_opt(
+            (parse_quantity, space0, DurationUnit::parse),
             |(num, _, unit)| match unit {
-                "days" | "day" => DateDuration::Days(num),
-                "weeks" | "week" => DateDuration::Weeks(num),
-                "months" | "month" => DateDuration::Months(num),
-                "years" | "year" => DateDuration::Years(num),
-                _ => unreachable!("all patterns have been matched"),
+                DurationUnit::Days => Some(DateDuration::Days(num)),
+                DurationUnit::Weeks => Some(DateDuration::Weeks(num)),
+                DurationUnit::Fortnights => Some(DateDuration::Fortnights(num)),
+                DurationUnit::Months => Some(DateDuration::Months(num)),
+                DurationUnit::Quarters => Some(DateDuration::Quarters(num)),
+                DurationUnit::Years => Some(DateDuration::Years(num)),
+                DurationUnit::Decades => Some(DateDuration::Decades(num)),
+                DurationUnit::Centuries => Some(DateDuration::Centuries(num)),
+                _ => None,
             },
         )
         .parse(input)
     }
+
+    pub fn to_span(&self) -> Result<jiff::Span, jiff::Error> {
+        let span = jiff::Span::new();
+        match self {
+            DateDuration::Days(days) => span.try_days(*days),
+            DateDuration::Weeks(weeks) => span.try_weeks(*weeks),
+            DateDuration::Fortnights(fortnights) => span.try_weeks(i64::from(*fortnights) * 2),
+            DateDuration::Months(months) => span.try_months(*months),
+            DateDuration::Quarters(quarters) => span.try_months(i64::from(*quarters) * 3),
+            DateDuration::Years(years) => span.try_years(*years),
+            DateDuration::Decades(decades) => span.try_years(i64::from(*decades) * 10),
+            DateDuration::Centuries(centuries) => span.try_years(i64::from(*centuries) * 100),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -124,6 +127,56 @@ mod tests {
         assert!(matches!(out, Ok(("", DateDuration::Years(300)))));
     }
 
+    #[test]
+    fn parse_long_units() {
+        let out = DateDuration::parse("1 fortnight");
+        assert!(matches!(out, Ok(("", DateDuration::Fortnights(1)))));
+
+        let out = DateDuration::parse("2 quarters");
+        assert!(matches!(out, Ok(("", DateDuration::Quarters(2)))));
+
+        let out = DateDuration::parse("3qtr");
+        assert!(matches!(out, Ok(("", DateDuration::Quarters(3)))));
+
+        let out = DateDuration::parse("3 decades");
+        assert!(matches!(out, Ok(("", DateDuration::Decades(3)))));
+
+        let out = DateDuration::parse("a century");
+        assert!(matches!(out, Ok(("", DateDuration::Centuries(1)))));
+    }
+
+    #[test]
+    fn long_units_to_span() {
+        let span = DateDuration::Fortnights(3).to_span().unwrap();
+        assert_eq!(span.get_weeks(), 6);
+
+        let span = DateDuration::Quarters(2).to_span().unwrap();
+        assert_eq!(span.get_months(), 6);
+
+        let span = DateDuration::Decades(3).to_span().unwrap();
+        assert_eq!(span.get_years(), 30);
+
+        let span = DateDuration::Centuries(1).to_span().unwrap();
+        assert_eq!(span.get_years(), 100);
+
+        assert!(DateDuration::Centuries(1000).to_span().is_err());
+    }
+
+    #[test]
+    fn parse_words() {
+        let out = DateDuration::parse("a week");
+        assert!(matches!(out, Ok(("", DateDuration::Weeks(1)))));
+
+        let out = DateDuration::parse("two days");
+        assert!(matches!(out, Ok(("", DateDuration::Days(2)))));
+
+        let out = DateDuration::parse("a couple of days");
+        assert!(matches!(out, Ok(("", DateDuration::Days(2)))));
+
+        let out = DateDuration::parse("thirty-one days");
+        assert!(matches!(out, Ok(("", DateDuration::Days(31)))));
+    }
+
     #[test]
     fn parse_unknown() {
         let input = "unknown";
@@ -1,12 +1,16 @@
 use nom::{branch::alt, bytes::complete::tag, combinator::map, Parser};
 
-use crate::NomResult;
+use crate::{EvalContext, Granularity, NomResult};
 
 #[derive(Debug)]
 pub enum DateKind {
     Week,
     Month,
+    Quarter,
+    Half,
     Year,
+    /// A year that starts in the context's fiscal year start month.
+    FiscalYear,
 }
 
 impl DateKind {
@@ -14,10 +18,51 @@ impl DateKind {
         alt((
             map(tag("week"), |_| DateKind::Week),
             map(tag("month"), |_| DateKind::Month),
+            map(tag("quarter"), |_| DateKind::Quarter),
+            map(tag("half"), |_| DateKind::Half),
             map(tag("year"), |_| DateKind::Year),
+            map(tag("fiscal year"), |_| DateKind::FiscalYear),
         ))
         .parse(input)
     }
+
+    pub fn granularity(&self) -> Granularity {
+        match self {
+            DateKind::Week => Granularity::Week,
+            DateKind::Month => Granularity::Month,
+            DateKind::Quarter => Granularity::Quarter,
+            DateKind::Half => Granularity::Half,
+            DateKind::Year | DateKind::FiscalYear => Granularity::Year,
+        }
+    }
+
+    /// The first day of the period `periods` away from the one containing
+    /// `today`, so 0 is the current period and -1 the previous one.
+    pub fn start(
+        &self,
+        ctx: &EvalContext,
+        today: jiff::civil::Date,
+        periods: i32,
+    ) -> Result<jiff::civil::Date, jiff::Error> {
+        use jiff::ToSpan;
+
+        let (months, first_month) = match self {
+            DateKind::Week => {
+                let into_week = i32::from(today.weekday().since(ctx.week_start));
+                return today.checked_add((periods * 7 - into_week).days());
+            }
+            DateKind::Month => (1, 1),
+            DateKind::Quarter => (3, 1),
+            DateKind::Half => (6, 1),
+            DateKind::Year => (12, 1),
+            DateKind::FiscalYear => (12, ctx.fiscal_year_start),
+        };
+
+        let into_period = i32::from(today.month() - first_month).rem_euclid(months);
+        today
+            .first_of_month()
+            .checked_add((periods * months - into_period).months())
+    }
 }
 
 #[cfg(test)]
@@ -25,7 +70,7 @@ mod tests {
     use insta::assert_debug_snapshot;
     use nom::Finish;
 
-    use crate::DateKind;
+    use crate::{DateKind, EvalContext};
 
     #[test]
     fn parse_week() {
@@ -45,6 +90,52 @@ mod tests {
         assert!(matches!(out, Ok(("", DateKind::Year))));
     }
 
+    #[test]
+    fn parse_quarter_and_half() {
+        let out = DateKind::parse("quarter");
+        assert!(matches!(out, Ok(("", DateKind::Quarter))));
+
+        let out = DateKind::parse("half");
+        assert!(matches!(out, Ok(("", DateKind::Half))));
+    }
+
+    #[test]
+    fn parse_fiscal_year() {
+        let out = DateKind::parse("fiscal year");
+        assert!(matches!(out, Ok(("", DateKind::FiscalYear))));
+    }
+
+    #[test]
+    fn start() {
+        let ctx = EvalContext {
+            fiscal_year_start: 10,
+            ..EvalContext::default()
+        };
+        // a Thursday
+        let today = jiff::civil::date(2025, 7, 3);
+
+        let cases = [
+            (DateKind::Week, 0, jiff::civil::date(2025, 6, 30)),
+            (DateKind::Week, 1, jiff::civil::date(2025, 7, 7)),
+            (DateKind::Month, 0, jiff::civil::date(2025, 7, 1)),
+            (DateKind::Month, -1, jiff::civil::date(2025, 6, 1)),
+            (DateKind::Quarter, 0, jiff::civil::date(2025, 7, 1)),
+            (DateKind::Quarter, -1, jiff::civil::date(2025, 4, 1)),
+            (DateKind::Half, 1, jiff::civil::date(2026, 1, 1)),
+            (DateKind::Year, 0, jiff::civil::date(2025, 1, 1)),
+            (DateKind::FiscalYear, 0, jiff::civil::date(2024, 10, 1)),
+            (DateKind::FiscalYear, 1, jiff::civil::date(2025, 10, 1)),
+        ];
+
+        for (kind, periods, expected) in cases {
+            assert_eq!(
+                kind.start(&ctx, today, periods).unwrap(),
+                expected,
+                "{kind:?} {periods}"
+            );
+        }
+    }
+
     #[test]
     fn parse_unknown() {
         let input = "unknown";
@@ -0,0 +1,229 @@
+use nom::{branch::alt, bytes::complete::tag, combinator::value, Parser};
+
+use crate::{DateDuration, NomResult, TimeDuration, WhenDuration};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationUnit {
+    Centuries,
+    Decades,
+    Years,
+    Quarters,
+    Months,
+    Fortnights,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+}
+
+impl DurationUnit {
+    pub fn parse(input: &str) -> NomResult<&str, DurationUnit> {
+        alt((
+            // centuries
+            value(
+                DurationUnit::Centuries,
+                alt((tag("centuries"), tag("century"))),
+            ),
+            // decades
+            value(DurationUnit::Decades, alt((tag("decades"), tag("decade")))),
+            // years
+            value(DurationUnit::Years, tag("years")),
+            value(DurationUnit::Years, tag("year")),
+            // quarters
+            value(
+                DurationUnit::Quarters,
+                alt((tag("quarters"), tag("quarter"), tag("qtrs"), tag("qtr"))),
+            ),
+            // months
+            value(DurationUnit::Months, tag("months")),
+            value(DurationUnit::Months, tag("month")),
+            // fortnights
+            value(
+                DurationUnit::Fortnights,
+                alt((tag("fortnights"), tag("fortnight"))),
+            ),
+            // weeks
+            value(DurationUnit::Weeks, tag("weeks")),
+            value(DurationUnit::Weeks, tag("week")),
+            // days
+            value(DurationUnit::Days, tag("days")),
+            value(DurationUnit::Days, tag("day")),
+            // hours
+            value(
+                DurationUnit::Hours,
+                alt((tag("hours"), tag("hour"), tag("hrs"), tag("hr"), tag("h"))),
+            ),
+            // milliseconds, before minutes so that "ms" is not read as "m"
+            value(
+                DurationUnit::Milliseconds,
+                alt((
+                    tag("milliseconds"),
+                    tag("millisecond"),
+                    tag("millis"),
+                    tag("msecs"),
+                    tag("msec"),
+                    tag("ms"),
+                )),
+            ),
+            // microseconds
+            value(
+                DurationUnit::Microseconds,
+                alt((
+                    tag("microseconds"),
+                    tag("microsecond"),
+                    tag("micros"),
+                    tag("usecs"),
+                    tag("usec"),
+                    tag("us"),
+                    tag("µs"),
+                )),
+            ),
+            // minutes
+            value(
+                DurationUnit::Minutes,
+                alt((
+                    tag("minutes"),
+                    tag("minute"),
+                    tag("mins"),
+                    tag("min"),
+                    tag("m"),
+                )),
+            ),
+            // seconds
+            value(
+                DurationUnit::Seconds,
+                alt((
+                    tag("seconds"),
+                    tag("second"),
+                    tag("secs"),
+                    tag("sec"),
+                    tag("s"),
+                )),
+            ),
+        ))
+        .parse(input)
+    }
+
+    pub fn to_duration(self, amount: u32) -> WhenDuration {
+        match self {
+            DurationUnit::Centuries => WhenDuration::Date(DateDuration::Centuries(amount)),
+            DurationUnit::Decades => WhenDuration::Date(DateDuration::Decades(amount)),
+            DurationUnit::Years => WhenDuration::Date(DateDuration::Years(amount)),
+            DurationUnit::Quarters => WhenDuration::Date(DateDuration::Quarters(amount)),
+            DurationUnit::Months => WhenDuration::Date(DateDuration::Months(amount)),
+            DurationUnit::Fortnights => WhenDuration::Date(DateDuration::Fortnights(amount)),
+            DurationUnit::Weeks => WhenDuration::Date(DateDuration::Weeks(amount)),
+            DurationUnit::Days => WhenDuration::Date(DateDuration::Days(amount)),
+            DurationUnit::Hours => WhenDuration::Time(TimeDuration::Hours(amount)),
+            DurationUnit::Minutes => WhenDuration::Time(TimeDuration::Minutes(amount)),
+            DurationUnit::Seconds => WhenDuration::Time(TimeDuration::Seconds(amount)),
+            DurationUnit::Milliseconds => WhenDuration::Time(TimeDuration::Milliseconds(amount)),
+            DurationUnit::Microseconds => WhenDuration::Time(TimeDuration::Microseconds(amount)),
+        }
+    }
+
+    /// The next smaller unit a fraction of this unit spills into, and how many
+    /// of them make up one of this unit.
+    ///
+    /// Months have no fixed length, so they have no smaller unit and
+    /// fractional months are rejected. Microseconds are the smallest unit and
+    /// fractional microseconds are rounded.
+    pub fn smaller(self) -> Option<(DurationUnit, u64)> {
+        match self {
+            DurationUnit::Centuries => Some((DurationUnit::Decades, 10)),
+            DurationUnit::Decades => Some((DurationUnit::Years, 10)),
+            DurationUnit::Years => Some((DurationUnit::Months, 12)),
+            DurationUnit::Quarters => Some((DurationUnit::Months, 3)),
+            DurationUnit::Months => None,
+            DurationUnit::Fortnights => Some((DurationUnit::Days, 14)),
+            DurationUnit::Weeks => Some((DurationUnit::Days, 7)),
+            DurationUnit::Days => Some((DurationUnit::Hours, 24)),
+            DurationUnit::Hours => Some((DurationUnit::Minutes, 60)),
+            DurationUnit::Minutes => Some((DurationUnit::Seconds, 60)),
+            DurationUnit::Seconds => Some((DurationUnit::Milliseconds, 1000)),
+            DurationUnit::Milliseconds => Some((DurationUnit::Microseconds, 1000)),
+            DurationUnit::Microseconds => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_debug_snapshot;
+    use nom::Finish;
+
+    use crate::DurationUnit;
+
+    #[test]
+    fn parse_date_units() {
+        let out = DurationUnit::parse("years");
+        assert!(matches!(out, Ok(("", DurationUnit::Years))));
+
+        let out = DurationUnit::parse("month");
+        assert!(matches!(out, Ok(("", DurationUnit::Months))));
+
+        let out = DurationUnit::parse("weeks");
+        assert!(matches!(out, Ok(("", DurationUnit::Weeks))));
+
+        let out = DurationUnit::parse("day");
+        assert!(matches!(out, Ok(("", DurationUnit::Days))));
+    }
+
+    #[test]
+    fn parse_time_units() {
+        let out = DurationUnit::parse("hrs");
+        assert!(matches!(out, Ok(("", DurationUnit::Hours))));
+
+        let out = DurationUnit::parse("min");
+        assert!(matches!(out, Ok(("", DurationUnit::Minutes))));
+
+        let out = DurationUnit::parse("m");
+        assert!(matches!(out, Ok(("", DurationUnit::Minutes))));
+
+        let out = DurationUnit::parse("s");
+        assert!(matches!(out, Ok(("", DurationUnit::Seconds))));
+    }
+
+    #[test]
+    fn parse_long_units() {
+        let out = DurationUnit::parse("centuries");
+        assert!(matches!(out, Ok(("", DurationUnit::Centuries))));
+
+        let out = DurationUnit::parse("decade");
+        assert!(matches!(out, Ok(("", DurationUnit::Decades))));
+
+        let out = DurationUnit::parse("qtr");
+        assert!(matches!(out, Ok(("", DurationUnit::Quarters))));
+
+        let out = DurationUnit::parse("fortnight");
+        assert!(matches!(out, Ok(("", DurationUnit::Fortnights))));
+    }
+
+    #[test]
+    fn parse_short_units() {
+        let out = DurationUnit::parse("ms");
+        assert!(matches!(out, Ok(("", DurationUnit::Milliseconds))));
+
+        let out = DurationUnit::parse("milliseconds");
+        assert!(matches!(out, Ok(("", DurationUnit::Milliseconds))));
+
+        let out = DurationUnit::parse("us");
+        assert!(matches!(out, Ok(("", DurationUnit::Microseconds))));
+
+        let out = DurationUnit::parse("µs");
+        assert!(matches!(out, Ok(("", DurationUnit::Microseconds))));
+
+        let out = DurationUnit::parse("microseconds");
+        assert!(matches!(out, Ok(("", DurationUnit::Microseconds))));
+    }
+
+    #[test]
+    fn parse_unknown() {
+        let input = "unknown";
+        let err = DurationUnit::parse(input).finish().unwrap_err();
+        assert_debug_snapshot!(err);
+    }
+}
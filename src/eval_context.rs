@@ -0,0 +1,93 @@
+use crate::{NamedTimes, YearBias};
+
+/// Everything outside of the parsed input that is needed to resolve it to a timestamp.
+#[derive(Debug, Clone)]
+pub struct EvalContext {
+    /// The instant relative expressions such as `tomorrow` or `in 2 days` are measured from.
+    pub now: jiff::Timestamp,
+    /// The timezone used when the input does not name one.
+    pub timezone: jiff::tz::TimeZone,
+    /// The first day of the week, used by `this week` and `this <weekday>`.
+    pub week_start: jiff::civil::Weekday,
+    /// The clock times of named times of day such as `noon`.
+    pub named_times: NamedTimes,
+    /// How a local time that is skipped or repeated by a timezone transition is resolved.
+    pub disambiguation: jiff::tz::Disambiguation,
+    /// Which year a date written without one falls in.
+    pub year_bias: YearBias,
+    /// The month, from 1 to 12, that fiscal years start in.
+    pub fiscal_year_start: i8,
+}
+
+impl EvalContext {
+    pub fn new(now: jiff::Timestamp, timezone: jiff::tz::TimeZone) -> Self {
+        EvalContext {
+            now,
+            timezone,
+            week_start: jiff::civil::Weekday::Monday,
+            named_times: NamedTimes::default(),
+            disambiguation: jiff::tz::Disambiguation::Compatible,
+            year_bias: YearBias::Current,
+            fiscal_year_start: 1,
+        }
+    }
+
+    /// The reference instant in the given timezone.
+    pub fn now_in(&self, timezone: jiff::tz::TimeZone) -> jiff::Zoned {
+        jiff::Zoned::new(self.now, timezone)
+    }
+
+    /// Moves `zoned` to the given wall clock time, resolving gaps and folds
+    /// with the context's disambiguation policy.
+    pub fn at_time(
+        &self,
+        zoned: &jiff::Zoned,
+        time: jiff::civil::Time,
+    ) -> Result<jiff::Zoned, jiff::Error> {
+        zoned
+            .with()
+            .time(time)
+            .disambiguation(self.disambiguation)
+            .build()
+    }
+
+    /// The first instant of `date` in the given timezone.
+    pub fn start_of_day(
+        &self,
+        date: jiff::civil::Date,
+        timezone: jiff::tz::TimeZone,
+    ) -> Result<jiff::Zoned, jiff::Error> {
+        timezone
+            .to_ambiguous_zoned(date.to_datetime(jiff::civil::Time::midnight()))
+            .disambiguate(self.disambiguation)
+    }
+}
+
+impl Default for EvalContext {
+    fn default() -> Self {
+        EvalContext::new(jiff::Timestamp::now(), jiff::tz::TimeZone::UTC)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EvalContext;
+
+    #[test]
+    fn at_time_uses_disambiguation() {
+        let tz = jiff::tz::TimeZone::get("America/New_York").unwrap();
+        let zoned = jiff::civil::date(2025, 3, 9).to_zoned(tz).unwrap();
+        // 02:30 does not exist on the day daylight saving time starts
+        let gap = jiff::civil::time(2, 30, 0, 0);
+
+        let ctx = EvalContext::default();
+        let compatible = ctx.at_time(&zoned, gap).unwrap();
+        assert_eq!(compatible.time(), jiff::civil::time(3, 30, 0, 0));
+
+        let ctx = EvalContext {
+            disambiguation: jiff::tz::Disambiguation::Reject,
+            ..ctx
+        };
+        assert!(ctx.at_time(&zoned, gap).is_err());
+    }
+}
@@ -1,39 +1,76 @@
 use nom::{
+    branch::alt,
     bytes::complete::tag,
-    character::complete::space1,
-    combinator::{all_consuming, map, opt},
+    character::complete::{digit1, satisfy, space0},
+    combinator::{map, map_opt, not, opt, verify},
     Parser,
 };
 
-use crate::{parse_hms, HmsFormat, NomResult};
+use crate::{oclock, parse_hms, EvalContext, HmsFormat, NomResult};
 
 #[derive(Debug)]
 pub struct GmtTime {
     pub hour: u8, // 0-23
     pub minute: u8,
     pub second: u8,
+    pub nanosecond: u32,
 }
 
 fn gmt(input: &str) -> NomResult<&str, &str> {
     tag("GMT").parse(input)
 }
 
+/// A four digit military time such as "0830" or "1730h".
+fn military(input: &str) -> NomResult<&str, (u8, u8, u8, u32)> {
+    map_opt(
+        (
+            verify(digit1, |digits: &str| digits.len() == 4),
+            opt(alt((tag("hrs"), tag("h")))),
+        ),
+        |(digits, _): (&str, _)| {
+            let hour = digits[..2].parse::<u8>().ok().filter(|hour| *hour < 24)?;
+            let minute = digits[2..]
+                .parse::<u8>()
+                .ok()
+                .filter(|minute| *minute < 60)?;
+            Some((hour, minute, 0, 0))
+        },
+    )
+    .parse(input)
+}
+
 impl GmtTime {
     pub fn parse(input: &str) -> NomResult<&str, GmtTime> {
-        all_consuming(map(
-            (parse_hms(HmsFormat::H24), opt(space1), opt(gmt)),
-            |((hour, minute, second), _, _)| GmtTime {
+        map(
+            (
+                alt((military, parse_hms(HmsFormat::H24))),
+                oclock,
+                opt((space0, gmt)),
+                // so that "8pm" or "12th" are not read as a bare hour
+                not(satisfy(|c| c.is_alphanumeric())),
+            ),
+            |((hour, minute, second, nanosecond), _, _, _)| GmtTime {
                 hour,
                 minute,
                 second,
+                nanosecond,
             },
-        ))
+        )
         .parse(input)
     }
 
-    pub fn with_zoned(&self, zoned: jiff::Zoned) -> Result<jiff::Zoned, jiff::Error> {
-        let t = jiff::civil::time(self.hour as i8, self.minute as i8, self.second as i8, 0);
-        zoned.with().time(t).build()
+    pub fn with_zoned(
+        &self,
+        ctx: &EvalContext,
+        zoned: jiff::Zoned,
+    ) -> Result<jiff::Zoned, jiff::Error> {
+        let t = jiff::civil::time(
+            self.hour as i8,
+            self.minute as i8,
+            self.second as i8,
+            self.nanosecond as i32,
+        );
+        ctx.at_time(&zoned, t)
     }
 }
 
@@ -54,7 +91,8 @@ mod tests {
                 GmtTime {
                     hour: 1,
                     minute: 0,
-                    second: 0
+                    second: 0,
+                    nanosecond: 0
                 }
             ))
         ));
@@ -70,7 +108,8 @@ mod tests {
                 GmtTime {
                     hour: 1,
                     minute: 30,
-                    second: 0
+                    second: 0,
+                    nanosecond: 0
                 }
             ))
         ));
@@ -86,7 +125,8 @@ mod tests {
                 GmtTime {
                     hour: 1,
                     minute: 30,
-                    second: 24
+                    second: 24,
+                    nanosecond: 0
                 }
             ))
         ));
@@ -102,7 +142,123 @@ mod tests {
                 GmtTime {
                     hour: 1,
                     minute: 30,
-                    second: 24
+                    second: 24,
+                    nanosecond: 0
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_leaves_rest() {
+        let out = GmtTime::parse("22:30 in UTC");
+        assert!(matches!(
+            out,
+            Ok((
+                " in UTC",
+                GmtTime {
+                    hour: 22,
+                    minute: 30,
+                    second: 0,
+                    nanosecond: 0
+                }
+            ))
+        ));
+
+        let out = GmtTime::parse("9 on friday");
+        assert!(matches!(
+            out,
+            Ok((
+                " on friday",
+                GmtTime {
+                    hour: 9,
+                    minute: 0,
+                    second: 0,
+                    nanosecond: 0
+                }
+            ))
+        ));
+
+        assert!(GmtTime::parse("8pm").is_err());
+    }
+
+    #[test]
+    fn parse_fractional_seconds() {
+        let out = GmtTime::parse("12:30:45.123456 GMT");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                GmtTime {
+                    hour: 12,
+                    minute: 30,
+                    second: 45,
+                    nanosecond: 123_456_000
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_military() {
+        let out = GmtTime::parse("0830");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                GmtTime {
+                    hour: 8,
+                    minute: 30,
+                    second: 0,
+                    nanosecond: 0
+                }
+            ))
+        ));
+
+        let out = GmtTime::parse("1730h");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                GmtTime {
+                    hour: 17,
+                    minute: 30,
+                    second: 0,
+                    nanosecond: 0
+                }
+            ))
+        ));
+
+        let out = GmtTime::parse("2359 GMT");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                GmtTime {
+                    hour: 23,
+                    minute: 59,
+                    second: 0,
+                    nanosecond: 0
+                }
+            ))
+        ));
+
+        assert!(GmtTime::parse("2460").is_err());
+        assert!(GmtTime::parse("12345").is_err());
+    }
+
+    #[test]
+    fn parse_oclock() {
+        let out = GmtTime::parse("5 o'clock");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                GmtTime {
+                    hour: 5,
+                    minute: 0,
+                    second: 0,
+                    nanosecond: 0
                 }
             ))
         ));
@@ -0,0 +1,30 @@
+/// How much of the calendar a date names. A date always resolves to the
+/// first instant of that span.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Granularity {
+    Year,
+    Half,
+    Quarter,
+    Month,
+    Week,
+    Day,
+    /// A single instant, such as an epoch timestamp.
+    Instant,
+}
+
+impl Granularity {
+    /// The length of the span, or `None` for a single instant.
+    pub fn span(&self) -> Option<jiff::Span> {
+        use jiff::ToSpan;
+
+        match self {
+            Granularity::Year => Some(1.year()),
+            Granularity::Half => Some(6.months()),
+            Granularity::Quarter => Some(3.months()),
+            Granularity::Month => Some(1.month()),
+            Granularity::Week => Some(1.week()),
+            Granularity::Day => Some(1.day()),
+            Granularity::Instant => None,
+        }
+    }
+}
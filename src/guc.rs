@@ -0,0 +1,103 @@
+use std::ffi::CString;
+
+use pgrx::{
+    guc::{GucContext, GucFlags, GucRegistry, GucSetting},
+    PostgresGucEnum,
+};
+
+use crate::YearBias;
+
+/// Which PostgreSQL clock is used as "now" when evaluating relative expressions.
+#[derive(PostgresGucEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NowSource {
+    /// The start of the current transaction, same as `now()` and `transaction_timestamp()`.
+    #[name = c"transaction"]
+    Transaction,
+    /// The start of the current statement, same as `statement_timestamp()`.
+    #[name = c"statement"]
+    Statement,
+    /// The actual current time, same as `clock_timestamp()`.
+    #[name = c"clock"]
+    Clock,
+}
+
+/// Which year a date written without one, such as `March 3`, falls in.
+#[derive(PostgresGucEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YearBiasSetting {
+    /// The current year, even if the date has already passed.
+    #[name = c"current"]
+    Current,
+    /// The next time the date comes around.
+    #[name = c"next"]
+    Next,
+}
+
+impl From<YearBiasSetting> for YearBias {
+    fn from(setting: YearBiasSetting) -> Self {
+        match setting {
+            YearBiasSetting::Current => YearBias::Current,
+            YearBiasSetting::Next => YearBias::Next,
+        }
+    }
+}
+
+pub static NOW_SOURCE: GucSetting<NowSource> = GucSetting::<NowSource>::new(NowSource::Transaction);
+
+pub static DEFAULT_TIMEZONE: GucSetting<Option<CString>> =
+    GucSetting::<Option<CString>>::new(Some(c"session"));
+
+pub static YEAR_BIAS: GucSetting<YearBiasSetting> =
+    GucSetting::<YearBiasSetting>::new(YearBiasSetting::Current);
+
+pub static NAMED_TIMES: GucSetting<Option<CString>> = GucSetting::<Option<CString>>::new(None);
+
+pub static FISCAL_YEAR_START: GucSetting<i32> = GucSetting::<i32>::new(1);
+
+pub fn init() {
+    GucRegistry::define_enum_guc(
+        c"pg_when.now_source",
+        c"Which timestamp pg_when treats as \"now\".",
+        c"One of 'transaction' (the default), 'statement' or 'clock'.",
+        &NOW_SOURCE,
+        GucContext::Userset,
+        GucFlags::default(),
+    );
+
+    GucRegistry::define_string_guc(
+        c"pg_when.default_timezone",
+        c"Timezone used when an expression does not name one.",
+        c"Either 'session' (the default) to follow the TimeZone setting, or a timezone such as 'UTC', 'UTC+3' or 'Africa/Addis_Ababa'.",
+        &DEFAULT_TIMEZONE,
+        GucContext::Userset,
+        GucFlags::default(),
+    );
+
+    GucRegistry::define_string_guc(
+        c"pg_when.named_times",
+        c"Clock times of named times of day such as noon or morning.",
+        c"A comma separated list of name=HH:MM overrides, for example 'morning=08:00, evening=19:30'.",
+        &NAMED_TIMES,
+        GucContext::Userset,
+        GucFlags::default(),
+    );
+
+    GucRegistry::define_enum_guc(
+        c"pg_when.year_bias",
+        c"Which year a date without a year falls in.",
+        c"Either 'current' (the default) for the current year, or 'next' for the next time the date comes around.",
+        &YEAR_BIAS,
+        GucContext::Userset,
+        GucFlags::default(),
+    );
+
+    GucRegistry::define_int_guc(
+        c"pg_when.fiscal_year_start",
+        c"The month fiscal years start in.",
+        c"A month number from 1 (January, the default) to 12 (December).",
+        &FISCAL_YEAR_START,
+        1,
+        12,
+        GucContext::Userset,
+        GucFlags::default(),
+    );
+}
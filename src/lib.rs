@@ -6,20 +6,46 @@ mod date_duration;
 pub use date_duration::*;
 mod date_kind;
 pub use date_kind::*;
+mod duration_unit;
+pub use duration_unit::*;
+mod eval_context;
+pub use eval_context::*;
 mod gmt_time;
 pub use gmt_time::*;
+mod granularity;
+pub use granularity::*;
+mod guc;
+pub use guc::*;
 mod parse_hms;
 pub use parse_hms::*;
+mod parse_quantity;
+pub use parse_quantity::*;
+mod parse_year;
+pub use parse_year::*;
+mod spoken_time;
+pub use spoken_time::*;
 mod time_duration;
 pub use time_duration::*;
 mod time_kind;
 pub use time_kind::*;
 mod weekday;
 pub use weekday::*;
+mod year_bias;
+pub use year_bias::*;
+mod when_adjustment;
+pub use when_adjustment::*;
+mod when_boundary;
+pub use when_boundary::*;
 mod when_date;
 pub use when_date::*;
+mod when_duration;
+pub use when_duration::*;
+mod when_epoch;
+pub use when_epoch::*;
 mod when_exact_date;
 pub use when_exact_date::*;
+mod when_exact_period;
+pub use when_exact_period::*;
 mod when_exact_time;
 pub use when_exact_time::*;
 mod when_input;
@@ -28,6 +54,12 @@ mod when_named_timezone;
 pub use when_named_timezone::*;
 mod when_relative_date;
 pub use when_relative_date::*;
+mod when_nth_weekday;
+pub use when_nth_weekday::*;
+mod when_reporting_period;
+pub use when_reporting_period::*;
+mod when_rfc_datetime;
+pub use when_rfc_datetime::*;
 mod when_relative_time;
 pub use when_relative_time::*;
 mod when_time;
@@ -36,9 +68,15 @@ mod when_timezone;
 pub use when_timezone::*;
 mod when_utc_offset;
 pub use when_utc_offset::*;
+mod when_week_date;
+pub use when_week_date::*;
 mod when_is;
 pub use when_is::*;
+mod when_iso_datetime;
+pub use when_iso_datetime::*;
 mod nom_result;
 pub use nom_result::*;
 mod month;
 pub use month::*;
+mod named_times;
+pub use named_times::*;
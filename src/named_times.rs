@@ -0,0 +1,133 @@
+/// The clock times that named times of day such as `noon` or `morning` resolve to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedTimes {
+    pub noon: jiff::civil::Time,
+    pub morning: jiff::civil::Time,
+    pub afternoon: jiff::civil::Time,
+    pub evening: jiff::civil::Time,
+    pub night: jiff::civil::Time,
+    pub midnight: jiff::civil::Time,
+    pub dawn: jiff::civil::Time,
+    pub dusk: jiff::civil::Time,
+    pub lunchtime: jiff::civil::Time,
+    pub end_of_day: jiff::civil::Time,
+    pub close_of_business: jiff::civil::Time,
+    pub start_of_business: jiff::civil::Time,
+}
+
+impl Default for NamedTimes {
+    fn default() -> Self {
+        NamedTimes {
+            noon: jiff::civil::time(12, 0, 0, 0),
+            morning: jiff::civil::time(9, 0, 0, 0),
+            afternoon: jiff::civil::time(15, 0, 0, 0),
+            evening: jiff::civil::time(18, 0, 0, 0),
+            night: jiff::civil::time(21, 0, 0, 0),
+            midnight: jiff::civil::time(0, 0, 0, 0),
+            dawn: jiff::civil::time(6, 0, 0, 0),
+            dusk: jiff::civil::time(19, 0, 0, 0),
+            lunchtime: jiff::civil::time(12, 30, 0, 0),
+            end_of_day: jiff::civil::Time::MAX,
+            close_of_business: jiff::civil::time(17, 0, 0, 0),
+            start_of_business: jiff::civil::time(9, 0, 0, 0),
+        }
+    }
+}
+
+impl NamedTimes {
+    /// Applies a comma separated list of `name=HH:MM[:SS]` overrides such as
+    /// `morning=08:00, evening=19:30`.
+    ///
+    /// Names are the ones accepted in expressions, so `eod` and `end of day`
+    /// both override the end of day. `tonight` shares its time with `night`.
+    pub fn with_overrides(mut self, overrides: &str) -> Result<NamedTimes, jiff::Error> {
+        for entry in overrides.split(',') {
+            if entry.trim().is_empty() {
+                continue;
+            }
+
+            let Some((name, time)) = entry.split_once('=') else {
+                return Err(jiff::Error::from_args(format_args!(
+                    "named time override '{}' is not of the form name=HH:MM",
+                    entry.trim()
+                )));
+            };
+
+            let name = name.trim();
+            let Some(field) = self.get_mut(name) else {
+                return Err(jiff::Error::from_args(format_args!(
+                    "unknown named time '{name}'"
+                )));
+            };
+
+            *field = time.trim().parse()?;
+        }
+
+        Ok(self)
+    }
+
+    fn get_mut(&mut self, name: &str) -> Option<&mut jiff::civil::Time> {
+        let name = name
+            .split(|c: char| c.is_whitespace() || c == '_')
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+
+        let field = match name.as_str() {
+            "noon" => &mut self.noon,
+            "morning" => &mut self.morning,
+            "afternoon" => &mut self.afternoon,
+            "evening" => &mut self.evening,
+            "night" | "tonight" => &mut self.night,
+            "midnight" => &mut self.midnight,
+            "dawn" => &mut self.dawn,
+            "dusk" => &mut self.dusk,
+            "lunchtime" => &mut self.lunchtime,
+            "end of day" | "eod" => &mut self.end_of_day,
+            "close of business" | "cob" => &mut self.close_of_business,
+            "start of business" => &mut self.start_of_business,
+            _ => return None,
+        };
+
+        Some(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NamedTimes;
+
+    #[test]
+    fn with_overrides() {
+        let named_times = NamedTimes::default()
+            .with_overrides("morning=08:00, evening = 19:30:15,EOD=17:00, close_of_business=16:00")
+            .unwrap();
+
+        assert_eq!(named_times.morning, jiff::civil::time(8, 0, 0, 0));
+        assert_eq!(named_times.evening, jiff::civil::time(19, 30, 15, 0));
+        assert_eq!(named_times.end_of_day, jiff::civil::time(17, 0, 0, 0));
+        assert_eq!(
+            named_times.close_of_business,
+            jiff::civil::time(16, 0, 0, 0)
+        );
+        assert_eq!(named_times.noon, NamedTimes::default().noon);
+    }
+
+    #[test]
+    fn with_empty_overrides() {
+        let named_times = NamedTimes::default().with_overrides("").unwrap();
+        assert_eq!(named_times, NamedTimes::default());
+    }
+
+    #[test]
+    fn with_invalid_overrides() {
+        assert!(NamedTimes::default()
+            .with_overrides("brunch=11:00")
+            .is_err());
+        assert!(NamedTimes::default().with_overrides("morning").is_err());
+        assert!(NamedTimes::default()
+            .with_overrides("morning=25:00")
+            .is_err());
+    }
+}
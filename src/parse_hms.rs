@@ -1,7 +1,8 @@
 use nom::{
+    branch::alt,
     bytes::complete::tag,
-    character::complete::digit1,
-    combinator::{map_res, verify},
+    character::complete::{digit1, space0},
+    combinator::{map_res, opt, value, verify},
     sequence::preceded,
     Parser,
 };
@@ -26,31 +27,81 @@ pub fn parse_60(input: &str) -> NomResult<&str, u8> {
     verify(map_res(digit1, |s: &str| s.parse::<u8>()), |num| *num < 60).parse(input)
 }
 
+/// An optional "o'clock" after an hour, as in "5 o'clock".
+pub fn oclock(input: &str) -> NomResult<&str, ()> {
+    value(
+        (),
+        opt((space0, alt((tag("o'clock"), tag("o’clock"), tag("oclock"))))),
+    )
+    .parse(input)
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum HmsFormat {
     H24,
     H12,
 }
 
-pub fn parse_hms(format: HmsFormat) -> impl Fn(&str) -> NomResult<&str, (u8, u8, u8)> {
+/// Parses the digits after a decimal point as nanoseconds, so ".5" is
+/// 500,000,000 and ".123456" is 123,456,000.
+pub fn parse_fraction(input: &str) -> NomResult<&str, u32> {
+    map_res(
+        preceded(tag("."), verify(digit1, |digits: &str| digits.len() <= 9)),
+        |digits: &str| format!("{digits:0<9}").parse::<u32>(),
+    )
+    .parse(input)
+}
+
+/// Parses `H[:MM[:SS[.fffffffff]]]` into hour, minute, second and nanosecond.
+pub fn parse_hms(format: HmsFormat) -> impl Fn(&str) -> NomResult<&str, (u8, u8, u8, u32)> {
     move |input: &str| parse_hms_inner(input, format)
 }
 
-fn parse_hms_inner(input: &str, format: HmsFormat) -> NomResult<&str, (u8, u8, u8)> {
+fn parse_hms_inner(input: &str, format: HmsFormat) -> NomResult<&str, (u8, u8, u8, u32)> {
     let (input, hour) = match format {
         HmsFormat::H24 => parse_24(input)?,
         HmsFormat::H12 => parse_12(input)?,
     };
 
     if !input.starts_with(':') {
-        return Ok((input, (hour, 0, 0)));
+        return Ok((input, (hour, 0, 0, 0)));
     }
 
     let (input, minute) = preceded(tag(":"), parse_60).parse(input)?;
     if !input.starts_with(':') {
-        return Ok((input, (hour, minute, 0)));
+        return Ok((input, (hour, minute, 0, 0)));
     }
 
     let (input, second) = preceded(tag(":"), parse_60).parse(input)?;
-    Ok((input, (hour, minute, second)))
+    if !input.starts_with('.') {
+        return Ok((input, (hour, minute, second, 0)));
+    }
+
+    let (input, nanosecond) = parse_fraction(input)?;
+    Ok((input, (hour, minute, second, nanosecond)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_hms, HmsFormat};
+
+    #[test]
+    fn parse_fraction() {
+        let out = parse_hms(HmsFormat::H24)("12:30:45.123456");
+        assert!(matches!(out, Ok(("", (12, 30, 45, 123_456_000)))));
+
+        let out = parse_hms(HmsFormat::H24)("12:30:45.5");
+        assert!(matches!(out, Ok(("", (12, 30, 45, 500_000_000)))));
+
+        let out = parse_hms(HmsFormat::H24)("12:30:45.123456789");
+        assert!(matches!(out, Ok(("", (12, 30, 45, 123_456_789)))));
+
+        assert!(parse_hms(HmsFormat::H24)("12:30:45.1234567891").is_err());
+    }
+
+    #[test]
+    fn fraction_needs_seconds() {
+        let out = parse_hms(HmsFormat::H24)("12:30.5");
+        assert!(matches!(out, Ok((".5", (12, 30, 0, 0)))));
+    }
 }
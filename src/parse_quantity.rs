@@ -0,0 +1,174 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, digit1, space1},
+    combinator::{map, map_opt, map_res, opt, value},
+    Parser,
+};
+
+use crate::NomResult;
+
+fn units(word: &str) -> Option<u32> {
+    let n = match word {
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        _ => return None,
+    };
+    Some(n)
+}
+
+fn teens(word: &str) -> Option<u32> {
+    let n = match word {
+        "zero" => 0,
+        "ten" => 10,
+        "eleven" => 11,
+        "twelve" => 12,
+        "thirteen" => 13,
+        "fourteen" => 14,
+        "fifteen" => 15,
+        "sixteen" => 16,
+        "seventeen" => 17,
+        "eighteen" => 18,
+        "nineteen" => 19,
+        _ => return None,
+    };
+    Some(n)
+}
+
+fn tens(word: &str) -> Option<u32> {
+    let n = match word {
+        "twenty" => 20,
+        "thirty" => 30,
+        "forty" => 40,
+        "fifty" => 50,
+        "sixty" => 60,
+        "seventy" => 70,
+        "eighty" => 80,
+        "ninety" => 90,
+        _ => return None,
+    };
+    Some(n)
+}
+
+/// Cardinal number words from `zero` to `ninety-nine`, the tens and units
+/// joined by a hyphen or a space.
+fn cardinal(input: &str) -> NomResult<&str, u32> {
+    alt((
+        map(
+            (
+                map_opt(alpha1, tens),
+                alt((tag("-"), space1)),
+                map_opt(alpha1, units),
+            ),
+            |(tens, _, units)| tens + units,
+        ),
+        map_opt(alpha1, |word: &str| {
+            units(word).or_else(|| teens(word)).or_else(|| tens(word))
+        }),
+    ))
+    .parse(input)
+}
+
+/// Indefinite articles and `a couple (of)`.
+fn article(input: &str) -> NomResult<&str, u32> {
+    alt((
+        value(
+            2,
+            (tag("a"), space1, tag("couple"), opt((space1, tag("of")))),
+        ),
+        map_opt(alpha1, |word: &str| match word {
+            "a" | "an" => Some(1),
+            _ => None,
+        }),
+    ))
+    .parse(input)
+}
+
+/// Digits or a number word, without the articles `parse_quantity` accepts.
+pub fn parse_number(input: &str) -> NomResult<&str, u32> {
+    alt((cardinal, map_res(digit1, |s: &str| s.parse::<u32>()))).parse(input)
+}
+
+/// The amount in front of a duration unit: digits, a number word or an article.
+pub fn parse_quantity(input: &str) -> NomResult<&str, u32> {
+    alt((
+        article,
+        cardinal,
+        map_res(digit1, |s: &str| s.parse::<u32>()),
+    ))
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_debug_snapshot;
+    use nom::Finish;
+
+    use crate::{parse_number, parse_quantity};
+
+    #[test]
+    fn parse_digits() {
+        let out = parse_quantity("10");
+        assert!(matches!(out, Ok(("", 10))));
+    }
+
+    #[test]
+    fn parse_articles() {
+        let out = parse_quantity("a week");
+        assert!(matches!(out, Ok((" week", 1))));
+
+        let out = parse_quantity("an hour");
+        assert!(matches!(out, Ok((" hour", 1))));
+
+        let out = parse_quantity("a couple of days");
+        assert!(matches!(out, Ok((" days", 2))));
+
+        let out = parse_quantity("a couple days");
+        assert!(matches!(out, Ok((" days", 2))));
+    }
+
+    #[test]
+    fn parse_cardinals() {
+        let out = parse_quantity("two days");
+        assert!(matches!(out, Ok((" days", 2))));
+
+        let out = parse_quantity("twelve minutes");
+        assert!(matches!(out, Ok((" minutes", 12))));
+
+        let out = parse_quantity("twenty days");
+        assert!(matches!(out, Ok((" days", 20))));
+
+        let out = parse_quantity("ninety-nine");
+        assert!(matches!(out, Ok(("", 99))));
+
+        let out = parse_quantity("forty two");
+        assert!(matches!(out, Ok(("", 42))));
+    }
+
+    #[test]
+    fn parse_number_without_articles() {
+        let out = parse_number("twenty past");
+        assert!(matches!(out, Ok((" past", 20))));
+
+        let out = parse_number("15");
+        assert!(matches!(out, Ok(("", 15))));
+
+        for input in ["a", "an", "a couple"] {
+            assert!(parse_number(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn parse_unknown() {
+        let input = "unknown";
+        let err = parse_quantity(input).finish().unwrap_err();
+        assert_debug_snapshot!(err);
+    }
+}
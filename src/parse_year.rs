@@ -0,0 +1,73 @@
+use nom::{
+    bytes::complete::take_while_m_n,
+    character::complete::satisfy,
+    combinator::{map_res, not, verify},
+    sequence::terminated,
+    Parser,
+};
+
+use crate::NomResult;
+
+/// A four digit year that stands on its own, so "2027-03-01", "1730h" and
+/// "0830" are left for the date and time parsers.
+pub fn parse_period_year(input: &str) -> NomResult<&str, u32> {
+    terminated(
+        map_res(
+            verify(
+                take_while_m_n(4, 4, |c: char| c.is_ascii_digit()),
+                |s: &str| !s.starts_with('0'),
+            ),
+            |s: &str| s.parse::<u32>(),
+        ),
+        not(satisfy(|c| {
+            c.is_alphanumeric() || matches!(c, ':' | '.' | '-' | '/')
+        })),
+    )
+    .parse(input)
+}
+
+/// A two digit year, where `00` to `49` are 2000-2049 and `50` to `99` are
+/// 1950-1999.
+pub fn parse_two_digit_year(input: &str) -> NomResult<&str, u32> {
+    map_res(
+        take_while_m_n(2, 2, |c: char| c.is_ascii_digit()),
+        |s: &str| {
+            s.parse::<u32>().map(|year| match year {
+                0..=49 => 2000 + year,
+                _ => 1900 + year,
+            })
+        },
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_period_year, parse_two_digit_year};
+
+    #[test]
+    fn period_years() {
+        let out = parse_period_year("2027 at noon");
+        assert!(matches!(out, Ok((" at noon", 2027))));
+
+        for input in ["2027-03-01", "2027/03/01", "1730h", "0830", "202", "20270"] {
+            assert!(parse_period_year(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn two_digit_years() {
+        let cases = [
+            ("00", 2000),
+            ("27", 2027),
+            ("49", 2049),
+            ("50", 1950),
+            ("99", 1999),
+        ];
+
+        for (input, year) in cases {
+            let out = parse_two_digit_year(input);
+            assert!(matches!(out, Ok(("", y)) if y == year), "{input}");
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{satisfy, space0, space1},
+    combinator::{map, not, opt, value, verify},
+    sequence::{preceded, terminated},
+    Parser,
+};
+
+use crate::{parse_number, AmPm, EvalContext, NomResult};
+
+#[derive(Debug, Clone, Copy)]
+pub enum SpokenRelation {
+    Past,
+    To,
+}
+
+/// A time said the way it is spoken, such as "half past 3" or "quarter to 9 pm".
+#[derive(Debug)]
+pub struct SpokenTime {
+    pub minutes: u8, // 1-59
+    pub relation: SpokenRelation,
+    pub hour: u8, // 0-23, or 1-12 with a period
+    pub period: Option<AmPm>,
+}
+
+fn minutes(input: &str) -> NomResult<&str, u8> {
+    alt((
+        value(30, tag("half")),
+        value(15, preceded(opt((tag("a"), space1)), tag("quarter"))),
+        terminated(
+            map(
+                verify(parse_number, |minutes| (1..60).contains(minutes)),
+                |minutes| minutes as u8,
+            ),
+            opt((space1, alt((tag("minutes"), tag("minute"))))),
+        ),
+    ))
+    .parse(input)
+}
+
+fn relation(input: &str) -> NomResult<&str, SpokenRelation> {
+    alt((
+        value(SpokenRelation::Past, tag("past")),
+        value(SpokenRelation::To, tag("to")),
+    ))
+    .parse(input)
+}
+
+fn hour(input: &str) -> NomResult<&str, u8> {
+    map(verify(parse_number, |hour| *hour < 24), |hour| hour as u8).parse(input)
+}
+
+impl SpokenTime {
+    pub fn parse(input: &str) -> NomResult<&str, SpokenTime> {
+        map(
+            (
+                minutes,
+                space1,
+                relation,
+                space1,
+                hour,
+                opt(preceded(space0, AmPm::parse)),
+                not(satisfy(|c| c.is_alphanumeric())),
+            ),
+            |(minutes, _, relation, _, hour, period, _)| SpokenTime {
+                minutes,
+                relation,
+                hour,
+                period,
+            },
+        )
+        .parse(input)
+    }
+
+    pub fn with_zoned(
+        &self,
+        ctx: &EvalContext,
+        zoned: jiff::Zoned,
+    ) -> Result<jiff::Zoned, jiff::Error> {
+        let hour24 = match self.period {
+            Some(ref period) => period.hour24(self.hour)?,
+            None => self.hour,
+        };
+
+        let minutes = i32::from(hour24) * 60;
+        let minutes = match self.relation {
+            SpokenRelation::Past => minutes + i32::from(self.minutes),
+            SpokenRelation::To => minutes - i32::from(self.minutes),
+        };
+
+        // "quarter to 0" is 23:45 on the day before
+        let (zoned, minutes) = if minutes < 0 {
+            let zoned = zoned
+                .with()
+                .date(zoned.date().yesterday()?)
+                .disambiguation(ctx.disambiguation)
+                .build()?;
+            (zoned, minutes + 24 * 60)
+        } else {
+            (zoned, minutes)
+        };
+
+        let t = jiff::civil::time((minutes / 60) as i8, (minutes % 60) as i8, 0, 0);
+        ctx.at_time(&zoned, t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_debug_snapshot;
+    use nom::Finish;
+
+    use crate::{AmPm, EvalContext, SpokenRelation, SpokenTime};
+
+    #[test]
+    fn parse_half_past() {
+        let out = SpokenTime::parse("half past 3");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                SpokenTime {
+                    minutes: 30,
+                    relation: SpokenRelation::Past,
+                    hour: 3,
+                    period: None
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_quarter_to() {
+        let out = SpokenTime::parse("quarter to 9");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                SpokenTime {
+                    minutes: 15,
+                    relation: SpokenRelation::To,
+                    hour: 9,
+                    period: None
+                }
+            ))
+        ));
+
+        let out = SpokenTime::parse("a quarter to nine pm");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                SpokenTime {
+                    minutes: 15,
+                    relation: SpokenRelation::To,
+                    hour: 9,
+                    period: Some(AmPm::Pm)
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_minutes_past() {
+        let out = SpokenTime::parse("10 past 4");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                SpokenTime {
+                    minutes: 10,
+                    relation: SpokenRelation::Past,
+                    hour: 4,
+                    period: None
+                }
+            ))
+        ));
+
+        let out = SpokenTime::parse("twenty minutes to 6pm");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                SpokenTime {
+                    minutes: 20,
+                    relation: SpokenRelation::To,
+                    hour: 6,
+                    period: Some(AmPm::Pm)
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn to_time() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+        let zoned = now.to_zoned(jiff::tz::TimeZone::UTC);
+
+        let cases = [
+            ("half past 3", jiff::civil::time(3, 30, 0, 0)),
+            ("quarter to 9", jiff::civil::time(8, 45, 0, 0)),
+            ("quarter to 9 pm", jiff::civil::time(20, 45, 0, 0)),
+            ("quarter to 1 pm", jiff::civil::time(12, 45, 0, 0)),
+        ];
+
+        for (input, time) in cases {
+            let (_, out) = SpokenTime::parse(input).unwrap();
+            let timestamp = out.with_zoned(&ctx, zoned.clone()).unwrap();
+            assert_eq!(timestamp.date(), zoned.date(), "{input}");
+            assert_eq!(timestamp.time(), time, "{input}");
+        }
+    }
+
+    #[test]
+    fn to_midnight_is_the_day_before() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+        let zoned = now.to_zoned(jiff::tz::TimeZone::UTC);
+
+        let (_, out) = SpokenTime::parse("quarter to 0").unwrap();
+        let timestamp = out.with_zoned(&ctx, zoned).unwrap();
+        assert_eq!(
+            timestamp.datetime(),
+            jiff::civil::date(2025, 7, 2).at(23, 45, 0, 0)
+        );
+    }
+
+    #[test]
+    fn parse_invalid_minutes() {
+        assert!(SpokenTime::parse("60 past 3").is_err());
+        assert!(SpokenTime::parse("half past 24").is_err());
+    }
+
+    #[test]
+    fn parse_articles_as_numbers() {
+        for input in ["half past a", "an to 9", "a couple past 3"] {
+            assert!(SpokenTime::parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn parse_unknown() {
+        let input = "unknown";
+        let err = SpokenTime::parse(input).finish().unwrap_err();
+        assert_debug_snapshot!(err);
+    }
+}
@@ -1,15 +1,11 @@
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::{digit1, space0},
-    combinator::{map, map_res},
-    Parser,
-};
+use nom::{character::complete::space0, combinator::map_opt, Parser};
 
-use crate::NomResult;
+use crate::{parse_quantity, DurationUnit, NomResult};
 
 #[derive(Debug)]
 pub enum TimeDuration {
+    Microseconds(u32),
+    Milliseconds(u32),
     Seconds(u32),
     Minutes(u32),
     Hours(u32),
@@ -17,40 +13,30 @@ pub enum TimeDuration {
 
 impl TimeDuration {
     pub fn parse(input: &str) -> NomResult<&str, TimeDuration> {
-        map(
-            (
-                map_res(digit1, |s: &str| s.parse::<u32>()),
-                space0,
-                alt((
-                    // seconds
-                    tag("seconds"),
-                    tag("second"),
-                    tag("secs"),
-                    tag("sec"),
-                    tag("s"),
-                    // minutes
-                    tag("minutes"),
-                    tag("minute"),
-                    tag("mins"),
-                    tag("min"),
-                    tag("m"),
-                    // hours
-                    tag("hours"),
-                    tag("hour"),
-                    tag("hrs"),
-                    tag("hr"),
-                    tag("h"),
-                )),
-            ),
+        map_opt(
+            (parse_quantity, space0, DurationUnit::parse),
             |(num, _, unit)| match unit {
-                "seconds" | "second" | "secs" | "sec" | "s" => TimeDuration::Seconds(num),
-                "minutes" | "minute" | "mins" | "min" | "m" => TimeDuration::Minutes(num),
-                "hours" | "hour" | "hrs" | "hr" | "h" => TimeDuration::Hours(num),
-                _ => unreachable!("all patterns have been matched"),
+                DurationUnit::Microseconds => Some(TimeDuration::Microseconds(num)),
+                DurationUnit::Milliseconds => Some(TimeDuration::Milliseconds(num)),
+                DurationUnit::Seconds => Some(TimeDuration::Seconds(num)),
+                DurationUnit::Minutes => Some(TimeDuration::Minutes(num)),
+                DurationUnit::Hours => Some(TimeDuration::Hours(num)),
+                _ => None,
             },
         )
         .parse(input)
     }
+
+    pub fn to_span(&self) -> Result<jiff::Span, jiff::Error> {
+        let span = jiff::Span::new();
+        match self {
+            TimeDuration::Microseconds(micros) => span.try_microseconds(*micros),
+            TimeDuration::Milliseconds(millis) => span.try_milliseconds(*millis),
+            TimeDuration::Seconds(secs) => span.try_seconds(*secs),
+            TimeDuration::Minutes(mins) => span.try_minutes(*mins),
+            TimeDuration::Hours(hrs) => span.try_hours(*hrs),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -60,6 +46,36 @@ mod tests {
 
     use crate::time_duration::TimeDuration;
 
+    #[test]
+    fn parse_microseconds() {
+        let out = TimeDuration::parse("250 microseconds");
+        assert!(matches!(out, Ok(("", TimeDuration::Microseconds(250)))));
+
+        let out = TimeDuration::parse("1 microsecond");
+        assert!(matches!(out, Ok(("", TimeDuration::Microseconds(1)))));
+
+        let out = TimeDuration::parse("100 us");
+        assert!(matches!(out, Ok(("", TimeDuration::Microseconds(100)))));
+
+        let out = TimeDuration::parse("300µs");
+        assert!(matches!(out, Ok(("", TimeDuration::Microseconds(300)))));
+    }
+
+    #[test]
+    fn parse_milliseconds() {
+        let out = TimeDuration::parse("500 ms");
+        assert!(matches!(out, Ok(("", TimeDuration::Milliseconds(500)))));
+
+        let out = TimeDuration::parse("1 millisecond");
+        assert!(matches!(out, Ok(("", TimeDuration::Milliseconds(1)))));
+
+        let out = TimeDuration::parse("100 milliseconds");
+        assert!(matches!(out, Ok(("", TimeDuration::Milliseconds(100)))));
+
+        let out = TimeDuration::parse("300ms");
+        assert!(matches!(out, Ok(("", TimeDuration::Milliseconds(300)))));
+    }
+
     #[test]
     fn parse_seconds() {
         let out = TimeDuration::parse("0 seconds");
@@ -114,6 +130,18 @@ mod tests {
         assert!(matches!(out, Ok(("", TimeDuration::Hours(300)))));
     }
 
+    #[test]
+    fn parse_words() {
+        let out = TimeDuration::parse("an hour");
+        assert!(matches!(out, Ok(("", TimeDuration::Hours(1)))));
+
+        let out = TimeDuration::parse("twelve minutes");
+        assert!(matches!(out, Ok(("", TimeDuration::Minutes(12)))));
+
+        let out = TimeDuration::parse("forty-five seconds");
+        assert!(matches!(out, Ok(("", TimeDuration::Seconds(45)))));
+    }
+
     #[test]
     fn parse_unknown() {
         let input = "unknown";
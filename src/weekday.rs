@@ -16,13 +16,19 @@ pub enum Weekday {
 impl Weekday {
     pub fn parse(input: &str) -> NomResult<&str, Weekday> {
         alt((
-            map(tag("monday"), |_| Weekday::Monday),
-            map(tag("tuesday"), |_| Weekday::Tuesday),
-            map(tag("wednesday"), |_| Weekday::Wednesday),
-            map(tag("thursday"), |_| Weekday::Thursday),
-            map(tag("friday"), |_| Weekday::Friday),
-            map(tag("saturday"), |_| Weekday::Saturday),
-            map(tag("sunday"), |_| Weekday::Sunday),
+            map(alt((tag("monday"), tag("Monday"))), |_| Weekday::Monday),
+            map(alt((tag("tuesday"), tag("Tuesday"))), |_| Weekday::Tuesday),
+            map(alt((tag("wednesday"), tag("Wednesday"))), |_| {
+                Weekday::Wednesday
+            }),
+            map(alt((tag("thursday"), tag("Thursday"))), |_| {
+                Weekday::Thursday
+            }),
+            map(alt((tag("friday"), tag("Friday"))), |_| Weekday::Friday),
+            map(alt((tag("saturday"), tag("Saturday"))), |_| {
+                Weekday::Saturday
+            }),
+            map(alt((tag("sunday"), tag("Sunday"))), |_| Weekday::Sunday),
         ))
         .parse(input)
     }
@@ -38,6 +44,18 @@ impl Weekday {
             Weekday::Sunday => 7,
         }
     }
+
+    pub fn to_jiff(&self) -> jiff::civil::Weekday {
+        match self {
+            Weekday::Monday => jiff::civil::Weekday::Monday,
+            Weekday::Tuesday => jiff::civil::Weekday::Tuesday,
+            Weekday::Wednesday => jiff::civil::Weekday::Wednesday,
+            Weekday::Thursday => jiff::civil::Weekday::Thursday,
+            Weekday::Friday => jiff::civil::Weekday::Friday,
+            Weekday::Saturday => jiff::civil::Weekday::Saturday,
+            Weekday::Sunday => jiff::civil::Weekday::Sunday,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -83,6 +101,12 @@ mod tests {
         assert!(matches!(out, Ok(("", Weekday::Saturday))));
     }
 
+    #[test]
+    fn parse_capitalized() {
+        let out = Weekday::parse("Friday");
+        assert!(matches!(out, Ok(("", Weekday::Friday))));
+    }
+
     #[test]
     fn parse_unknown() {
         let input = "unknown";
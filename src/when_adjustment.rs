@@ -0,0 +1,241 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{space0, space1},
+    combinator::map,
+    Parser,
+};
+
+use crate::{parse_quantity, EvalContext, NomResult, WhenDuration};
+
+/// An amount that a resolved expression is moved by.
+#[derive(Debug)]
+pub enum AdjustmentAmount {
+    Duration(WhenDuration),
+    /// Monday to Friday, skipping weekends.
+    BusinessDays(u32),
+}
+
+/// A trailing `+ <amount>` or `- <amount>`, as in `next monday at 9am + 2h30m`
+/// or `end of month - 3 business days`.
+#[derive(Debug)]
+pub enum WhenAdjustment {
+    Add(AdjustmentAmount),
+    Subtract(AdjustmentAmount),
+}
+
+fn business_days(input: &str) -> NomResult<&str, u32> {
+    map(
+        (
+            parse_quantity,
+            space1,
+            alt((tag("business"), tag("working"))),
+            space1,
+            alt((tag("days"), tag("day"))),
+        ),
+        |(days, ..)| days,
+    )
+    .parse(input)
+}
+
+fn amount(input: &str) -> NomResult<&str, AdjustmentAmount> {
+    alt((
+        map(business_days, AdjustmentAmount::BusinessDays),
+        map(WhenDuration::parse, AdjustmentAmount::Duration),
+    ))
+    .parse(input)
+}
+
+fn is_weekend(date: jiff::civil::Date) -> bool {
+    matches!(
+        date.weekday(),
+        jiff::civil::Weekday::Saturday | jiff::civil::Weekday::Sunday
+    )
+}
+
+/// Moves `zoned` by `days` business days, keeping its clock time. From a
+/// weekend, counting starts at the business day before it when moving forward
+/// and after it when moving back, so saturday + 1 business day is monday.
+fn add_business_days(
+    ctx: &EvalContext,
+    zoned: &jiff::Zoned,
+    days: i64,
+) -> Result<jiff::Zoned, jiff::Error> {
+    use jiff::ToSpan;
+
+    if days == 0 {
+        return Ok(zoned.clone());
+    }
+
+    let step = days.signum();
+    let mut date = zoned.date();
+    while is_weekend(date) {
+        date = date.checked_sub(step.days())?;
+    }
+
+    // every 5 business days are a whole week
+    date = date.checked_add((days / 5 * 7).days())?;
+    for _ in 0..(days % 5).abs() {
+        date = date.checked_add(step.days())?;
+        while is_weekend(date) {
+            date = date.checked_add(step.days())?;
+        }
+    }
+
+    zoned
+        .with()
+        .date(date)
+        .disambiguation(ctx.disambiguation)
+        .build()
+}
+
+impl WhenAdjustment {
+    pub fn parse(input: &str) -> NomResult<&str, WhenAdjustment> {
+        alt((
+            map((tag("+"), space0, amount), |(_, _, amount)| {
+                WhenAdjustment::Add(amount)
+            }),
+            map((tag("-"), space0, amount), |(_, _, amount)| {
+                WhenAdjustment::Subtract(amount)
+            }),
+        ))
+        .parse(input)
+    }
+
+    pub fn apply(&self, ctx: &EvalContext, zoned: jiff::Zoned) -> Result<jiff::Zoned, jiff::Error> {
+        match self {
+            WhenAdjustment::Add(AdjustmentAmount::Duration(duration)) => {
+                zoned.checked_add(duration.to_span()?)
+            }
+            WhenAdjustment::Subtract(AdjustmentAmount::Duration(duration)) => {
+                zoned.checked_sub(duration.to_span()?)
+            }
+            WhenAdjustment::Add(AdjustmentAmount::BusinessDays(days)) => {
+                add_business_days(ctx, &zoned, i64::from(*days))
+            }
+            WhenAdjustment::Subtract(AdjustmentAmount::BusinessDays(days)) => {
+                add_business_days(ctx, &zoned, -i64::from(*days))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        assert_resolves, AdjustmentAmount, DateDuration, EvalContext, TimeDuration, WhenAdjustment,
+        WhenDuration,
+    };
+
+    #[test]
+    fn parse_duration() {
+        let out = WhenAdjustment::parse("+ 2h30m");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenAdjustment::Add(AdjustmentAmount::Duration(WhenDuration::Compound(_)))
+            ))
+        ));
+
+        let out = WhenAdjustment::parse("-1 day");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenAdjustment::Subtract(AdjustmentAmount::Duration(WhenDuration::Date(
+                    DateDuration::Days(1)
+                )))
+            ))
+        ));
+
+        let out = WhenAdjustment::parse("+ 90 minutes");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenAdjustment::Add(AdjustmentAmount::Duration(WhenDuration::Time(
+                    TimeDuration::Minutes(90)
+                )))
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_business_days() {
+        let out = WhenAdjustment::parse("- 3 business days");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenAdjustment::Subtract(AdjustmentAmount::BusinessDays(3))
+            ))
+        ));
+
+        let out = WhenAdjustment::parse("+ one working day");
+        assert!(matches!(
+            out,
+            Ok(("", WhenAdjustment::Add(AdjustmentAmount::BusinessDays(1))))
+        ));
+    }
+
+    #[test]
+    fn apply_business_days() {
+        let ctx = EvalContext::default();
+        let tz = jiff::tz::TimeZone::UTC;
+
+        let cases = [
+            // from a wednesday
+            ((2025, 7, 2), "+ 1 business day", (2025, 7, 3)),
+            ((2025, 7, 2), "+ 3 business days", (2025, 7, 7)),
+            ((2025, 7, 2), "+ 5 business days", (2025, 7, 9)),
+            ((2025, 7, 2), "+ 12 business days", (2025, 7, 18)),
+            ((2025, 7, 2), "- 3 business days", (2025, 6, 27)),
+            ((2025, 7, 2), "+ 0 business days", (2025, 7, 2)),
+            // from a saturday
+            ((2025, 7, 5), "+ 1 business day", (2025, 7, 7)),
+            ((2025, 7, 5), "+ 5 business days", (2025, 7, 11)),
+            ((2025, 7, 5), "- 1 business day", (2025, 7, 4)),
+            // from a monday
+            ((2025, 7, 7), "- 1 business day", (2025, 7, 4)),
+        ];
+
+        for ((y, m, d), input, (ey, em, ed)) in cases {
+            let zoned = jiff::civil::date(y, m, d)
+                .at(9, 30, 0, 0)
+                .to_zoned(tz.clone())
+                .unwrap();
+            let (_, adjustment) = WhenAdjustment::parse(input).unwrap();
+            let out = adjustment.apply(&ctx, zoned).unwrap();
+            assert_eq!(
+                out.datetime(),
+                jiff::civil::date(ey, em, ed).at(9, 30, 0, 0),
+                "{y}-{m}-{d} {input}"
+            );
+        }
+    }
+
+    #[test]
+    fn trailing_adjustments() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let cases = [
+            ("next monday at 9am + 2h30m", "2025-07-07T11:30:00Z"),
+            (
+                "end of month - 3 business days",
+                "2025-07-28T23:59:59.999999999Z",
+            ),
+            ("tomorrow + 1 day - 2 hours", "2025-07-05T00:22:00Z"),
+            ("now + 2h30m + 15m", "2025-07-03T05:07:00Z"),
+            ("2026-01-01 - 1 day", "2025-12-31T00:00:00Z"),
+            ("friday +1 working day", "2025-07-07T02:22:00Z"),
+            (
+                "today at noon + 3 business days in Asia/Tokyo",
+                "2025-07-08T03:00:00Z",
+            ),
+        ];
+
+        assert_resolves(&ctx, &cases);
+    }
+}
@@ -0,0 +1,335 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{satisfy, space1},
+    combinator::{map, not, opt, value},
+    sequence::terminated,
+    Parser,
+};
+
+use crate::{DateKind, EvalContext, Granularity, NomResult, WhenDate, WhenMonthRef};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edge {
+    Start,
+    End,
+}
+
+/// The start or the end of a period, such as `start of next month`,
+/// `end of March 2027` or `the last day of february`.
+///
+/// A period starts at the first instant of its first day and ends at the last
+/// nanosecond before the next period starts.
+#[derive(Debug)]
+pub enum WhenBoundary {
+    /// `end of last quarter`, `start of this week` or `end of the year`.
+    Kind {
+        edge: Edge,
+        periods: i32,
+        kind: DateKind,
+    },
+    /// The period a date names, as in `end of March 2027` or `start of tomorrow`.
+    Date { edge: Edge, date: Box<WhenDate> },
+    /// `the first day of next month` or `the last day of february`, which is
+    /// a whole day rather than an instant.
+    DayOfMonth { edge: Edge, month: WhenMonthRef },
+}
+
+/// `start of`, `beginning of` or `end of`, with an optional leading "the".
+fn edge_of(input: &str) -> NomResult<&str, Edge> {
+    map(
+        (
+            opt((tag("the"), space1)),
+            alt((
+                value(Edge::Start, alt((tag("start"), tag("beginning")))),
+                value(Edge::End, tag("end")),
+            )),
+            space1,
+            tag("of"),
+            space1,
+        ),
+        |(_, edge, ..)| edge,
+    )
+    .parse(input)
+}
+
+fn periods(input: &str) -> NomResult<&str, i32> {
+    map(
+        opt(terminated(
+            alt((
+                value(0, alt((tag("the"), tag("this")))),
+                value(1, tag("next")),
+                value(-1, tag("last")),
+            )),
+            space1,
+        )),
+        |periods| periods.unwrap_or(0),
+    )
+    .parse(input)
+}
+
+fn parse_kind(input: &str) -> NomResult<&str, WhenBoundary> {
+    map(
+        (
+            edge_of,
+            periods,
+            terminated(DateKind::parse, not(satisfy(|c| c.is_alphanumeric()))),
+        ),
+        |(edge, periods, kind)| WhenBoundary::Kind {
+            edge,
+            periods,
+            kind,
+        },
+    )
+    .parse(input)
+}
+
+fn parse_date(input: &str) -> NomResult<&str, WhenBoundary> {
+    map((edge_of, WhenDate::parse), |(edge, date)| {
+        WhenBoundary::Date {
+            edge,
+            date: Box::new(date),
+        }
+    })
+    .parse(input)
+}
+
+fn parse_day_of_month(input: &str) -> NomResult<&str, WhenBoundary> {
+    map(
+        (
+            opt((tag("the"), space1)),
+            alt((
+                value(Edge::Start, tag("first")),
+                value(Edge::End, tag("last")),
+            )),
+            space1,
+            tag("day"),
+            space1,
+            tag("of"),
+            space1,
+            WhenMonthRef::parse,
+        ),
+        |(_, edge, _, _, _, _, _, month)| WhenBoundary::DayOfMonth { edge, month },
+    )
+    .parse(input)
+}
+
+/// The first instant of the period of `granularity` starting on `start`, or
+/// the last instant before the next one.
+fn boundary(
+    ctx: &EvalContext,
+    edge: Edge,
+    start: jiff::civil::Date,
+    granularity: Granularity,
+    timezone: jiff::tz::TimeZone,
+) -> Result<jiff::Zoned, jiff::Error> {
+    use jiff::ToSpan;
+
+    match (edge, granularity.span()) {
+        (Edge::Start, _) | (_, None) => ctx.start_of_day(start, timezone),
+        (Edge::End, Some(span)) => {
+            let next = ctx.start_of_day(start.checked_add(span)?, timezone)?;
+            next.checked_sub(1.nanosecond())
+        }
+    }
+}
+
+impl WhenBoundary {
+    pub fn parse(input: &str) -> NomResult<&str, WhenBoundary> {
+        alt((parse_kind, parse_date, parse_day_of_month)).parse(input)
+    }
+
+    pub fn granularity(&self) -> Granularity {
+        match self {
+            WhenBoundary::Kind { .. } | WhenBoundary::Date { .. } => Granularity::Instant,
+            WhenBoundary::DayOfMonth { .. } => Granularity::Day,
+        }
+    }
+
+    pub fn to_timestamp(
+        &self,
+        ctx: &EvalContext,
+        timezone: jiff::tz::TimeZone,
+    ) -> Result<jiff::Zoned, jiff::Error> {
+        let today = ctx.now_in(timezone.clone()).date();
+
+        match self {
+            WhenBoundary::Kind {
+                edge,
+                periods,
+                kind,
+            } => {
+                let start = kind.start(ctx, today, *periods)?;
+                boundary(ctx, *edge, start, kind.granularity(), timezone)
+            }
+            WhenBoundary::Date { edge, date } => {
+                let zoned = date.to_timestamp(ctx, timezone.clone())?;
+                match date.granularity() {
+                    // an instant is its own start and end
+                    Granularity::Instant => Ok(zoned),
+                    granularity => boundary(ctx, *edge, zoned.date(), granularity, timezone),
+                }
+            }
+            WhenBoundary::DayOfMonth { edge, month } => {
+                let first = month.first_day(ctx, today)?;
+                let date = match edge {
+                    Edge::Start => first,
+                    Edge::End => first.last_of_month(),
+                };
+                ctx.start_of_day(date, timezone)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        assert_resolves, DateKind, Edge, EvalContext, WhenBoundary, WhenDate, WhenMonthRef,
+    };
+
+    #[test]
+    fn parse_kind() {
+        let out = WhenBoundary::parse("start of this week");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenBoundary::Kind {
+                    edge: Edge::Start,
+                    periods: 0,
+                    kind: DateKind::Week
+                }
+            ))
+        ));
+
+        let out = WhenBoundary::parse("beginning of next month");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenBoundary::Kind {
+                    edge: Edge::Start,
+                    periods: 1,
+                    kind: DateKind::Month
+                }
+            ))
+        ));
+
+        let out = WhenBoundary::parse("the end of last quarter");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenBoundary::Kind {
+                    edge: Edge::End,
+                    periods: -1,
+                    kind: DateKind::Quarter
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_date() {
+        let out = WhenBoundary::parse("end of March 2027");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenBoundary::Date {
+                    edge: Edge::End,
+                    ..
+                }
+            ))
+        ));
+
+        let out = WhenBoundary::parse("start of tomorrow");
+        assert!(matches!(
+            out,
+            Ok(("", WhenBoundary::Date { edge: Edge::Start, date })) if matches!(*date, WhenDate::Relative(_))
+        ));
+    }
+
+    #[test]
+    fn parse_day_of_month() {
+        let out = WhenBoundary::parse("the last day of february");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenBoundary::DayOfMonth {
+                    edge: Edge::End,
+                    month: WhenMonthRef::Named {
+                        month: 2,
+                        year: None
+                    }
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_not_a_boundary() {
+        for input in [
+            "end of day",
+            "start of business",
+            "end",
+            "the last friday of the month",
+        ] {
+            assert!(WhenBoundary::parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn to_timestamp() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let cases = [
+            ("start of this week", "2025-06-30T00:00:00Z"),
+            ("end of this week", "2025-07-06T23:59:59.999999999Z"),
+            ("beginning of next month", "2025-08-01T00:00:00Z"),
+            ("end of the month", "2025-07-31T23:59:59.999999999Z"),
+            ("end of the year", "2025-12-31T23:59:59.999999999Z"),
+            ("end of last quarter", "2025-06-30T23:59:59.999999999Z"),
+            ("start of fiscal year", "2025-01-01T00:00:00Z"),
+            ("end of tomorrow", "2025-07-04T23:59:59.999999999Z"),
+            ("start of tomorrow", "2025-07-04T00:00:00Z"),
+            ("end of March 2027", "2027-03-31T23:59:59.999999999Z"),
+            ("end of 2026-W42", "2026-10-18T23:59:59.999999999Z"),
+            ("end of FY2027", "2027-12-31T23:59:59.999999999Z"),
+            ("start of @1700000000", "2023-11-14T22:13:20Z"),
+            ("the last day of february", "2025-02-28T00:00:00Z"),
+            ("the first day of next month", "2025-08-01T00:00:00Z"),
+            ("the last day of february 2028", "2028-02-29T00:00:00Z"),
+        ];
+
+        for (input, expected) in cases {
+            let (_, out) = WhenBoundary::parse(input).unwrap();
+            let zoned = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+            assert_eq!(zoned.timestamp(), expected.parse().unwrap(), "{input}");
+        }
+    }
+
+    #[test]
+    fn period_boundaries() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let cases = [
+            ("start of this week", "2025-06-30T00:00:00Z"),
+            (
+                "beginning of next month in Asia/Tokyo",
+                "2025-07-31T15:00:00Z",
+            ),
+            ("end of the year", "2025-12-31T23:59:59.999999999Z"),
+            ("end of day", "2025-07-03T23:59:59.999999999Z"),
+            ("end of last quarter", "2025-06-30T23:59:59.999999999Z"),
+            ("the last day of february at noon", "2025-02-28T12:00:00Z"),
+            ("this month", "2025-07-01T00:00:00Z"),
+        ];
+
+        assert_resolves(&ctx, &cases);
+    }
+}
@@ -1,26 +1,85 @@
-use nom::{branch::alt, combinator::map, Parser};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::space1,
+    combinator::{map, opt, peek},
+    sequence::preceded,
+    Parser,
+};
 
-use crate::{NomResult, WhenExactDate, WhenRelativeDate};
+use crate::{
+    EvalContext, Granularity, NomResult, Weekday, WhenBoundary, WhenEpoch, WhenExactDate,
+    WhenExactPeriod, WhenNthWeekday, WhenRelativeDate, WhenReportingPeriod, WhenWeekDate,
+};
 
 #[derive(Debug)]
 pub enum WhenDate {
     Relative(WhenRelativeDate),
     Exact(WhenExactDate),
+    Period(WhenExactPeriod),
+    Epoch(WhenEpoch),
+    Week(WhenWeekDate),
+    Reporting(WhenReportingPeriod),
+    NthWeekday(WhenNthWeekday),
+    Boundary(WhenBoundary),
 }
 
 impl WhenDate {
     pub fn parse(input: &str) -> NomResult<&str, WhenDate> {
-        alt((
-            map(WhenRelativeDate::parse, WhenDate::Relative),
-            map(WhenExactDate::parse, WhenDate::Exact),
-        ))
+        preceded(
+            opt((tag("on"), space1)),
+            alt((
+                // "friday, march 3" is an exact date, not the upcoming friday
+                map(
+                    preceded(peek(Weekday::parse), WhenExactDate::parse),
+                    WhenDate::Exact,
+                ),
+                map(WhenEpoch::parse, WhenDate::Epoch),
+                // "the last day of february" is not "last" anything
+                map(WhenBoundary::parse, WhenDate::Boundary),
+                // before relative dates, so "next week 3" is not "next week" at 3:00
+                map(WhenWeekDate::parse, WhenDate::Week),
+                map(WhenReportingPeriod::parse, WhenDate::Reporting),
+                // "last friday of the month" is not "last friday"
+                map(WhenNthWeekday::parse, WhenDate::NthWeekday),
+                map(WhenRelativeDate::parse, WhenDate::Relative),
+                map(WhenExactPeriod::parse, WhenDate::Period),
+                map(WhenExactDate::parse, WhenDate::Exact),
+            )),
+        )
         .parse(input)
     }
 
-    pub fn to_timestamp(&self, timezone: jiff::tz::TimeZone) -> Result<jiff::Zoned, jiff::Error> {
+    pub fn granularity(&self) -> Granularity {
         match self {
-            WhenDate::Relative(when_relative_date) => when_relative_date.to_timestamp(timezone),
-            WhenDate::Exact(when_exact_date) => when_exact_date.to_timestamp(timezone),
+            WhenDate::Relative(when_relative_date) => when_relative_date.granularity(),
+            WhenDate::Exact(_) | WhenDate::NthWeekday(_) => Granularity::Day,
+            WhenDate::Period(when_exact_period) => when_exact_period.granularity(),
+            WhenDate::Epoch(_) => Granularity::Instant,
+            WhenDate::Week(when_week_date) => when_week_date.granularity(),
+            WhenDate::Reporting(when_reporting_period) => when_reporting_period.granularity(),
+            WhenDate::Boundary(when_boundary) => when_boundary.granularity(),
+        }
+    }
+
+    pub fn to_timestamp(
+        &self,
+        ctx: &EvalContext,
+        timezone: jiff::tz::TimeZone,
+    ) -> Result<jiff::Zoned, jiff::Error> {
+        match self {
+            WhenDate::Relative(when_relative_date) => {
+                when_relative_date.to_timestamp(ctx, timezone)
+            }
+            WhenDate::Exact(when_exact_date) => when_exact_date.to_timestamp(ctx, timezone),
+            WhenDate::Period(when_exact_period) => when_exact_period.to_timestamp(ctx, timezone),
+            WhenDate::Epoch(when_epoch) => Ok(when_epoch.to_timestamp(timezone)),
+            WhenDate::Week(when_week_date) => when_week_date.to_timestamp(ctx, timezone),
+            WhenDate::Reporting(when_reporting_period) => {
+                when_reporting_period.to_timestamp(ctx, timezone)
+            }
+            WhenDate::NthWeekday(when_nth_weekday) => when_nth_weekday.to_timestamp(ctx, timezone),
+            WhenDate::Boundary(when_boundary) => when_boundary.to_timestamp(ctx, timezone),
         }
     }
 }
@@ -30,7 +89,10 @@ mod tests {
     use insta::assert_debug_snapshot;
     use nom::Finish;
 
-    use crate::{DateDuration, WhenDate, WhenExactDate, WhenRelativeDate};
+    use crate::{
+        DateDuration, Granularity, Weekday, WhenDate, WhenDuration, WhenExactDate, WhenExactPeriod,
+        WhenRelativeDate,
+    };
 
     #[test]
     fn parse_relative() {
@@ -45,7 +107,9 @@ mod tests {
             out,
             Ok((
                 "",
-                WhenDate::Relative(WhenRelativeDate::Ago(DateDuration::Days(10)))
+                WhenDate::Relative(WhenRelativeDate::Ago(WhenDuration::Date(
+                    DateDuration::Days(10)
+                )))
             ))
         ));
     }
@@ -58,7 +122,8 @@ mod tests {
             Ok((
                 "",
                 WhenDate::Exact(WhenExactDate {
-                    year: 2001,
+                    weekday: None,
+                    year: Some(2001),
                     month: 10,
                     day: 10
                 })
@@ -71,12 +136,154 @@ mod tests {
             Ok((
                 "",
                 WhenDate::Exact(WhenExactDate {
-                    year: 2001,
+                    weekday: None,
+                    year: Some(2001),
+                    month: 10,
+                    day: 10
+                })
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_on() {
+        let out = WhenDate::parse("on friday");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenDate::Relative(WhenRelativeDate::UpcomingDay(Weekday::Friday))
+            ))
+        ));
+
+        let out = WhenDate::parse("on 10/10/2001");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenDate::Exact(WhenExactDate {
+                    weekday: None,
+                    year: Some(2001),
                     month: 10,
                     day: 10
                 })
             ))
         ));
+
+        let out = WhenDate::parse("one day ago");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenDate::Relative(WhenRelativeDate::Ago(WhenDuration::Date(
+                    DateDuration::Days(1)
+                )))
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_weekday_prefix() {
+        let out = WhenDate::parse("Friday, March 3, 2026");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenDate::Exact(WhenExactDate {
+                    weekday: Some(Weekday::Friday),
+                    year: Some(2026),
+                    month: 3,
+                    day: 3
+                })
+            ))
+        ));
+
+        let out = WhenDate::parse("friday, march 6, 2026");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenDate::Exact(WhenExactDate {
+                    weekday: Some(Weekday::Friday),
+                    year: Some(2026),
+                    month: 3,
+                    day: 6
+                })
+            ))
+        ));
+
+        let out = WhenDate::parse("friday at noon");
+        assert!(matches!(
+            out,
+            Ok((
+                " at noon",
+                WhenDate::Relative(WhenRelativeDate::UpcomingDay(Weekday::Friday))
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_period() {
+        let out = WhenDate::parse("March 2027");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenDate::Period(WhenExactPeriod {
+                    year: 2027,
+                    month: Some(3)
+                })
+            ))
+        ));
+
+        let out = WhenDate::parse("in 2027");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenDate::Period(WhenExactPeriod {
+                    year: 2027,
+                    month: None
+                })
+            ))
+        ));
+
+        let out = WhenDate::parse("in 2027 days");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenDate::Relative(WhenRelativeDate::In(WhenDuration::Date(
+                    DateDuration::Days(2027)
+                )))
+            ))
+        ));
+
+        let out = WhenDate::parse("2027-03-01");
+        assert!(matches!(out, Ok(("", WhenDate::Exact(_)))));
+    }
+
+    #[test]
+    fn granularity() {
+        let cases = [
+            ("in 2027", Granularity::Year),
+            ("year 2027", Granularity::Year),
+            ("March 2027", Granularity::Month),
+            ("next march", Granularity::Month),
+            ("March 3, 2027", Granularity::Day),
+            ("tomorrow", Granularity::Day),
+            ("@1700000000", Granularity::Instant),
+            ("2026-W42", Granularity::Week),
+            ("next week 3", Granularity::Day),
+            ("2026-291", Granularity::Day),
+            ("Q3 2026", Granularity::Quarter),
+            ("next quarter", Granularity::Quarter),
+        ];
+
+        for (input, granularity) in cases {
+            let (_, out) = WhenDate::parse(input).unwrap();
+            assert_eq!(out.granularity(), granularity, "{input}");
+        }
     }
 
     #[test]
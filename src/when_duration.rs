@@ -0,0 +1,401 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, space0, space1},
+    combinator::{map, map_opt, map_res, opt, value, verify},
+    multi::many0,
+    sequence::preceded,
+    Parser,
+};
+
+use crate::{DateDuration, DurationUnit, NomResult, TimeDuration};
+
+#[derive(Debug)]
+pub enum WhenDuration {
+    Date(DateDuration),
+    Time(TimeDuration),
+    /// Several terms such as `1 day 3 hours` or `1h30m`, added together.
+    Compound(Vec<WhenDuration>),
+}
+
+/// What may come between the terms of a compound duration: a comma, an
+/// `and`, plain whitespace or nothing at all.
+fn separator(input: &str) -> NomResult<&str, ()> {
+    value(
+        (),
+        alt((
+            value((), (space0, tag(","), space0, opt((tag("and"), space1)))),
+            value((), (space1, tag("and"), space1)),
+            value((), space0),
+        )),
+    )
+    .parse(input)
+}
+
+/// A decimal amount split into its whole part and the digits after the point.
+fn decimal(input: &str) -> NomResult<&str, (u32, &str)> {
+    (
+        map_res(digit1, |s: &str| s.parse::<u32>()),
+        preceded(tag("."), verify(digit1, |s: &str| s.len() <= 9)),
+    )
+        .parse(input)
+}
+
+/// Turns `whole.fraction unit` into whole units, spilling the fraction into
+/// the next smaller units until nothing is left.
+///
+/// A fraction of a year spills into months, and since months have no fixed
+/// length a fraction of a month is rejected, so `0.25 years` is 3 months
+/// while `1.5 months` and `0.1 years` do not parse. Fractions of a
+/// microsecond are rounded to the nearest microsecond.
+fn spill(whole: u32, fraction: &str, unit: DurationUnit) -> Option<WhenDuration> {
+    let mut numerator: u64 = fraction.parse().ok()?;
+    let denominator = 10u64.pow(fraction.len() as u32);
+
+    let mut whole = whole as u64;
+    let mut unit = unit;
+    let mut terms = Vec::new();
+
+    loop {
+        if numerator == 0 {
+            terms.push(unit.to_duration(whole.try_into().ok()?));
+            break;
+        }
+
+        match unit.smaller() {
+            Some((smaller, factor)) => {
+                if whole > 0 {
+                    terms.push(unit.to_duration(whole.try_into().ok()?));
+                }
+
+                let scaled = numerator * factor;
+                whole = scaled / denominator;
+                numerator = scaled % denominator;
+                unit = smaller;
+            }
+            None if unit == DurationUnit::Months => return None,
+            None => {
+                let rounded = whole + u64::from(numerator * 2 >= denominator);
+                terms.push(unit.to_duration(rounded.try_into().ok()?));
+                break;
+            }
+        }
+    }
+
+    if terms.len() == 1 {
+        terms.pop()
+    } else {
+        Some(WhenDuration::Compound(terms))
+    }
+}
+
+fn fractional(input: &str) -> NomResult<&str, WhenDuration> {
+    map_opt(
+        (decimal, space0, DurationUnit::parse),
+        |((whole, fraction), _, unit)| spill(whole, fraction, unit),
+    )
+    .parse(input)
+}
+
+fn term(input: &str) -> NomResult<&str, WhenDuration> {
+    alt((
+        fractional,
+        map(DateDuration::parse, WhenDuration::Date),
+        map(TimeDuration::parse, WhenDuration::Time),
+    ))
+    .parse(input)
+}
+
+/// Adds the fields of two spans without balancing units against each other,
+/// so that `1 month 40 days` stays as written.
+fn add_fields(a: jiff::Span, b: jiff::Span) -> Result<jiff::Span, jiff::Error> {
+    a.try_years(a.get_years() as i64 + b.get_years() as i64)?
+        .try_months(a.get_months() as i64 + b.get_months() as i64)?
+        .try_weeks(a.get_weeks() as i64 + b.get_weeks() as i64)?
+        .try_days(a.get_days() as i64 + b.get_days() as i64)?
+        .try_hours(a.get_hours() as i64 + b.get_hours() as i64)?
+        .try_minutes(a.get_minutes() + b.get_minutes())?
+        .try_seconds(a.get_seconds() + b.get_seconds())?
+        .try_milliseconds(a.get_milliseconds() + b.get_milliseconds())?
+        .try_microseconds(a.get_microseconds() + b.get_microseconds())?
+        .try_nanoseconds(a.get_nanoseconds() + b.get_nanoseconds())
+}
+
+impl WhenDuration {
+    pub fn parse(input: &str) -> NomResult<&str, WhenDuration> {
+        map((term, many0(preceded(separator, term))), |(first, rest)| {
+            if rest.is_empty() {
+                return first;
+            }
+
+            let mut terms = Vec::new();
+            for term in std::iter::once(first).chain(rest) {
+                match term {
+                    WhenDuration::Compound(inner) => terms.extend(inner),
+                    term => terms.push(term),
+                }
+            }
+            WhenDuration::Compound(terms)
+        })
+        .parse(input)
+    }
+
+    pub fn to_span(&self) -> Result<jiff::Span, jiff::Error> {
+        match self {
+            WhenDuration::Date(date_duration) => date_duration.to_span(),
+            WhenDuration::Time(time_duration) => time_duration.to_span(),
+            WhenDuration::Compound(durations) => durations
+                .iter()
+                .try_fold(jiff::Span::new(), |span, duration| {
+                    add_fields(span, duration.to_span()?)
+                }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_debug_snapshot;
+    use nom::Finish;
+
+    use crate::{DateDuration, TimeDuration, WhenDuration};
+
+    #[test]
+    fn parse_date() {
+        let out = WhenDuration::parse("10 days");
+        assert!(matches!(
+            out,
+            Ok(("", WhenDuration::Date(DateDuration::Days(10))))
+        ));
+
+        let out = WhenDuration::parse("2 months");
+        assert!(matches!(
+            out,
+            Ok(("", WhenDuration::Date(DateDuration::Months(2))))
+        ));
+    }
+
+    #[test]
+    fn parse_time() {
+        let out = WhenDuration::parse("30 minutes");
+        assert!(matches!(
+            out,
+            Ok(("", WhenDuration::Time(TimeDuration::Minutes(30))))
+        ));
+
+        let out = WhenDuration::parse("2 hours");
+        assert!(matches!(
+            out,
+            Ok(("", WhenDuration::Time(TimeDuration::Hours(2))))
+        ));
+
+        let out = WhenDuration::parse("45s");
+        assert!(matches!(
+            out,
+            Ok(("", WhenDuration::Time(TimeDuration::Seconds(45))))
+        ));
+    }
+
+    #[test]
+    fn parse_compound() {
+        let out = WhenDuration::parse("1 day 3 hours 20 minutes");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenDuration::Compound(ref terms)
+            )) if matches!(
+                terms.as_slice(),
+                [
+                    WhenDuration::Date(DateDuration::Days(1)),
+                    WhenDuration::Time(TimeDuration::Hours(3)),
+                    WhenDuration::Time(TimeDuration::Minutes(20)),
+                ]
+            )
+        ));
+
+        let out = WhenDuration::parse("2 weeks and 3 days");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenDuration::Compound(ref terms)
+            )) if matches!(
+                terms.as_slice(),
+                [
+                    WhenDuration::Date(DateDuration::Weeks(2)),
+                    WhenDuration::Date(DateDuration::Days(3)),
+                ]
+            )
+        ));
+
+        let out = WhenDuration::parse("1 year, 2 months, and 3 days");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenDuration::Compound(ref terms)
+            )) if terms.len() == 3
+        ));
+    }
+
+    #[test]
+    fn parse_compact() {
+        let out = WhenDuration::parse("1h30m");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenDuration::Compound(ref terms)
+            )) if matches!(
+                terms.as_slice(),
+                [
+                    WhenDuration::Time(TimeDuration::Hours(1)),
+                    WhenDuration::Time(TimeDuration::Minutes(30)),
+                ]
+            )
+        ));
+    }
+
+    #[test]
+    fn parse_fractional() {
+        let out = WhenDuration::parse("1.5 hours");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenDuration::Compound(ref terms)
+            )) if matches!(
+                terms.as_slice(),
+                [
+                    WhenDuration::Time(TimeDuration::Hours(1)),
+                    WhenDuration::Time(TimeDuration::Minutes(30)),
+                ]
+            )
+        ));
+
+        let out = WhenDuration::parse("2.5 days");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenDuration::Compound(ref terms)
+            )) if matches!(
+                terms.as_slice(),
+                [
+                    WhenDuration::Date(DateDuration::Days(2)),
+                    WhenDuration::Time(TimeDuration::Hours(12)),
+                ]
+            )
+        ));
+
+        let out = WhenDuration::parse("0.25 years");
+        assert!(matches!(
+            out,
+            Ok(("", WhenDuration::Date(DateDuration::Months(3))))
+        ));
+
+        let out = WhenDuration::parse("1.5 hours 10 minutes");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenDuration::Compound(ref terms)
+            )) if terms.len() == 3
+        ));
+    }
+
+    #[test]
+    fn parse_fractional_seconds() {
+        let out = WhenDuration::parse("1.6 seconds");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenDuration::Compound(ref terms)
+            )) if matches!(
+                terms.as_slice(),
+                [
+                    WhenDuration::Time(TimeDuration::Seconds(1)),
+                    WhenDuration::Time(TimeDuration::Milliseconds(600)),
+                ]
+            )
+        ));
+
+        let out = WhenDuration::parse("0.01 minutes");
+        assert!(matches!(
+            out,
+            Ok(("", WhenDuration::Time(TimeDuration::Milliseconds(600))))
+        ));
+
+        let out = WhenDuration::parse("0.0000005 seconds");
+        assert!(matches!(
+            out,
+            Ok(("", WhenDuration::Time(TimeDuration::Microseconds(1))))
+        ));
+
+        let out = WhenDuration::parse("1.5 decades");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenDuration::Compound(ref terms)
+            )) if matches!(
+                terms.as_slice(),
+                [
+                    WhenDuration::Date(DateDuration::Decades(1)),
+                    WhenDuration::Date(DateDuration::Years(5)),
+                ]
+            )
+        ));
+    }
+
+    #[test]
+    fn parse_fractional_months() {
+        let out = WhenDuration::parse("1.5 months");
+        assert!(out.is_err());
+
+        let out = WhenDuration::parse("0.1 years");
+        assert!(out.is_err());
+    }
+
+    #[test]
+    fn parse_stops_before_other_words() {
+        let out = WhenDuration::parse("2 hours ago");
+        assert!(matches!(
+            out,
+            Ok((" ago", WhenDuration::Time(TimeDuration::Hours(2))))
+        ));
+
+        let out = WhenDuration::parse("2 days and tomorrow");
+        assert!(matches!(
+            out,
+            Ok((" and tomorrow", WhenDuration::Date(DateDuration::Days(2))))
+        ));
+    }
+
+    #[test]
+    fn parse_unknown() {
+        let input = "unknown";
+        let err = WhenDuration::parse(input).finish().unwrap_err();
+        assert_debug_snapshot!(err);
+    }
+
+    #[test]
+    fn to_span() {
+        let (_, out) = WhenDuration::parse("2 hours").unwrap();
+        let span = out.to_span().unwrap();
+        assert_eq!(span.get_hours(), 2);
+
+        let (_, out) = WhenDuration::parse("3 weeks").unwrap();
+        let span = out.to_span().unwrap();
+        assert_eq!(span.get_weeks(), 3);
+
+        let (_, out) = WhenDuration::parse("1 day 3 hours 20 minutes 10 minutes").unwrap();
+        let span = out.to_span().unwrap();
+        assert_eq!(
+            span.fieldwise(),
+            jiff::Span::new().days(1).hours(3).minutes(30)
+        );
+    }
+}
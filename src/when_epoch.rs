@@ -0,0 +1,231 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, space0, space1},
+    combinator::{map, map_opt, map_res, opt, value},
+    sequence::preceded,
+    Parser,
+};
+
+use crate::{parse_fraction, NomResult};
+
+/// An instant written as time since the Unix epoch, such as `@1700000000`,
+/// `epoch 1700000000` or `1700000000 ms since epoch`.
+#[derive(Debug)]
+pub struct WhenEpoch {
+    pub timestamp: jiff::Timestamp,
+}
+
+/// The number of nanoseconds in one of each unit.
+fn unit(input: &str) -> NomResult<&str, i128> {
+    alt((
+        value(
+            1_000_000,
+            alt((
+                tag("milliseconds"),
+                tag("millisecond"),
+                tag("millis"),
+                tag("ms"),
+            )),
+        ),
+        value(
+            1_000,
+            alt((
+                tag("microseconds"),
+                tag("microsecond"),
+                tag("micros"),
+                tag("us"),
+                tag("µs"),
+            )),
+        ),
+        value(
+            1,
+            alt((
+                tag("nanoseconds"),
+                tag("nanosecond"),
+                tag("nanos"),
+                tag("ns"),
+            )),
+        ),
+        value(
+            1_000_000_000,
+            alt((
+                tag("seconds"),
+                tag("second"),
+                tag("secs"),
+                tag("sec"),
+                tag("s"),
+            )),
+        ),
+    ))
+    .parse(input)
+}
+
+/// A signed amount with optional fractional digits, scaled by `10^9`.
+fn amount(input: &str) -> NomResult<&str, i128> {
+    map_opt(
+        (opt(tag("-")), digit1, opt(parse_fraction)),
+        |(sign, whole, fraction): (Option<&str>, &str, Option<u32>)| {
+            let amount = whole
+                .parse::<i128>()
+                .ok()?
+                .checked_mul(NANOS_PER_SECOND)?
+                .checked_add(i128::from(fraction.unwrap_or(0)))?;
+            Some(if sign.is_some() { -amount } else { amount })
+        },
+    )
+    .parse(input)
+}
+
+/// `Timestamp::from_nanosecond` does not check its range, so the value is
+/// split into seconds and nanoseconds for `Timestamp::new`, which does.
+fn to_timestamp(amount: i128, nanos_per_unit: i128) -> Result<jiff::Timestamp, jiff::Error> {
+    let out_of_range = || jiff::Error::from_args(format_args!("epoch value is out of range"));
+    let nanos = amount
+        .checked_mul(nanos_per_unit)
+        .map(|nanos| nanos / NANOS_PER_SECOND)
+        .ok_or_else(out_of_range)?;
+    let seconds = i64::try_from(nanos.div_euclid(NANOS_PER_SECOND)).map_err(|_| out_of_range())?;
+    let subsec = nanos.rem_euclid(NANOS_PER_SECOND) as i32;
+    jiff::Timestamp::new(seconds, subsec)
+}
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+impl WhenEpoch {
+    /// `@` and `epoch` take seconds, while `since epoch` can name its unit.
+    pub fn parse(input: &str) -> NomResult<&str, WhenEpoch> {
+        map_res(
+            alt((
+                map(preceded(tag("@"), amount), |amount| {
+                    (amount, NANOS_PER_SECOND)
+                }),
+                map(preceded((tag("epoch"), space1), amount), |amount| {
+                    (amount, NANOS_PER_SECOND)
+                }),
+                map(
+                    (
+                        amount,
+                        space0,
+                        unit,
+                        space1,
+                        tag("since"),
+                        space1,
+                        opt((tag("the"), space1)),
+                        tag("epoch"),
+                    ),
+                    |(amount, _, unit, ..)| (amount, unit),
+                ),
+            )),
+            |(amount, unit)| to_timestamp(amount, unit).map(|timestamp| WhenEpoch { timestamp }),
+        )
+        .parse(input)
+    }
+
+    pub fn to_timestamp(&self, timezone: jiff::tz::TimeZone) -> jiff::Zoned {
+        self.timestamp.to_zoned(timezone)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_resolves, EvalContext, WhenEpoch};
+
+    #[test]
+    fn parse_seconds() {
+        let expected: jiff::Timestamp = "2023-11-14T22:13:20Z".parse().unwrap();
+
+        for input in [
+            "@1700000000",
+            "epoch 1700000000",
+            "1700000000 seconds since epoch",
+        ] {
+            let out = WhenEpoch::parse(input);
+            assert!(
+                matches!(out, Ok(("", WhenEpoch { timestamp })) if timestamp == expected),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_units() {
+        let cases = [
+            ("1700000000123 ms since epoch", "2023-11-14T22:13:20.123Z"),
+            (
+                "1700000000123456us since the epoch",
+                "2023-11-14T22:13:20.123456Z",
+            ),
+            (
+                "1700000000123456789 ns since epoch",
+                "2023-11-14T22:13:20.123456789Z",
+            ),
+            ("1.5 s since epoch", "1970-01-01T00:00:01.5Z"),
+        ];
+
+        for (input, expected) in cases {
+            let out = WhenEpoch::parse(input);
+            let expected: jiff::Timestamp = expected.parse().unwrap();
+            assert!(
+                matches!(out, Ok(("", WhenEpoch { timestamp })) if timestamp == expected),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_fractional_and_negative() {
+        let out = WhenEpoch::parse("@1700000000.123 at noon");
+        let expected: jiff::Timestamp = "2023-11-14T22:13:20.123Z".parse().unwrap();
+        assert!(matches!(out, Ok((" at noon", WhenEpoch { timestamp })) if timestamp == expected));
+
+        let out = WhenEpoch::parse("@-1.5");
+        let expected: jiff::Timestamp = "1969-12-31T23:59:58.5Z".parse().unwrap();
+        assert!(matches!(out, Ok(("", WhenEpoch { timestamp })) if timestamp == expected));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(WhenEpoch::parse("1700000000").is_err());
+        assert!(WhenEpoch::parse("@99999999999999999999").is_err());
+        assert!(WhenEpoch::parse("@99999999999999999999999999999999999999").is_err());
+    }
+
+    #[test]
+    fn parse_past_year_9999() {
+        for input in [
+            "@253402300800",
+            "@999999999999",
+            "@-999999999999",
+            "@99999999999999999",
+            "99999999999999999999999999 ns since epoch",
+        ] {
+            assert!(WhenEpoch::parse(input).is_err(), "{input}");
+        }
+
+        let out = WhenEpoch::parse("@253402207200.999999999");
+        assert!(
+            matches!(out, Ok(("", WhenEpoch { timestamp })) if timestamp == jiff::Timestamp::MAX)
+        );
+        assert!(WhenEpoch::parse("@253402207201").is_err());
+        assert!(WhenEpoch::parse("epoch").is_err());
+    }
+
+    #[test]
+    fn epoch_literals() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let cases = [
+            ("@1700000000", "2023-11-14T22:13:20Z"),
+            ("epoch 1700000000", "2023-11-14T22:13:20Z"),
+            ("1700000000000 ms since epoch", "2023-11-14T22:13:20Z"),
+            ("@1700000000.123", "2023-11-14T22:13:20.123Z"),
+            ("@1700000000 in Asia/Tokyo", "2023-11-14T22:13:20Z"),
+            ("@1700000000 at noon", "2023-11-14T12:00:00Z"),
+            ("@1700000000 at noon in Asia/Tokyo", "2023-11-15T03:00:00Z"),
+        ];
+
+        assert_resolves(&ctx, &cases);
+    }
+}
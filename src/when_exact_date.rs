@@ -1,39 +1,80 @@
 use nom::{
     branch::alt,
-    bytes::complete::tag,
-    character::complete::{digit1, space1},
-    combinator::{map, map_res, verify},
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::{digit1, satisfy, space1},
+    combinator::{map, map_res, not, opt, verify},
+    sequence::{preceded, terminated},
     Parser,
 };
 
-use crate::{Month, NomResult};
+use crate::{EvalContext, Month, NomResult, Weekday};
 
 #[derive(Debug)]
 pub struct WhenExactDate {
-    pub year: u32,
+    pub weekday: Option<Weekday>,
+    pub year: Option<u32>,
     pub month: u8,
     pub day: u8,
 }
 
 impl WhenExactDate {
     pub fn parse(input: &str) -> NomResult<&str, WhenExactDate> {
-        alt((
-            parse_with_dashes_dd_mm_yyyy,
-            parse_with_slashes_dd_mm_yyyy,
-            parse_mmm_dd_yyyy,
-            parse_with_dashes_yyyy_mm_dd,
-            parse_with_slashes_yyyy_mm_dd,
-            parse_dd_mmm_yyyy,
-        ))
+        map(
+            (
+                opt(weekday_prefix),
+                alt((
+                    parse_with_dashes_dd_mm_yyyy,
+                    parse_with_slashes_dd_mm_yyyy,
+                    parse_mmm_dd_yyyy,
+                    parse_with_dashes_yyyy_mm_dd,
+                    parse_with_slashes_yyyy_mm_dd,
+                    parse_ordinal_date,
+                    parse_dd_mmm_yyyy,
+                )),
+            ),
+            |(weekday, date)| WhenExactDate { weekday, ..date },
+        )
         .parse(input)
     }
 
-    pub fn to_timestamp(&self, timezone: jiff::tz::TimeZone) -> Result<jiff::Zoned, jiff::Error> {
-        let date = jiff::civil::date(self.year as i16, self.month as i8, self.day as i8);
-        date.to_zoned(timezone)
+    /// A date without a year is placed according to the context's year bias,
+    /// and a weekday that does not match the date is an error.
+    pub fn to_timestamp(
+        &self,
+        ctx: &EvalContext,
+        timezone: jiff::tz::TimeZone,
+    ) -> Result<jiff::Zoned, jiff::Error> {
+        let date = match self.year {
+            Some(year) => {
+                let year = i16::try_from(year)
+                    .map_err(|_| jiff::Error::from_args(format_args!("invalid year '{year}'")))?;
+                jiff::civil::Date::new(year, self.month as i8, self.day as i8)?
+            }
+            None => {
+                let today = ctx.now_in(timezone.clone()).date();
+                ctx.year_bias
+                    .resolve(today, self.month as i8, self.day as i8)?
+            }
+        };
+
+        if let Some(ref weekday) = self.weekday {
+            if date.weekday() != weekday.to_jiff() {
+                return Err(jiff::Error::from_args(format_args!(
+                    "{date} is a {:?}, not a {:?}",
+                    date.weekday(),
+                    weekday.to_jiff()
+                )));
+            }
+        }
+
+        ctx.start_of_day(date, timezone)
     }
 }
 
+fn weekday_prefix(input: &str) -> NomResult<&str, Weekday> {
+    terminated(Weekday::parse, (opt(tag(",")), space1)).parse(input)
+}
+
 fn parse_day(input: &str) -> NomResult<&str, u8> {
     verify(map_res(digit1, |s: &str| s.parse::<u8>()), |&day| {
         (1..=31).contains(&day)
@@ -41,6 +82,32 @@ fn parse_day(input: &str) -> NomResult<&str, u8> {
     .parse(input)
 }
 
+fn ordinal_suffix(day: u8) -> &'static str {
+    match (day % 10, day % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+/// A day with an optional ordinal suffix that has to match it, so "3rd" is
+/// accepted but "3th" is not.
+fn parse_ordinal_day(input: &str) -> NomResult<&str, u8> {
+    map(
+        verify(
+            (
+                parse_day,
+                opt(alt((tag("st"), tag("nd"), tag("rd"), tag("th")))),
+            ),
+            |&(day, suffix)| suffix.is_none() || suffix == Some(ordinal_suffix(day)),
+        ),
+        |(day, _)| day,
+    )
+    .parse(input)
+}
+
 fn parse_month(input: &str) -> NomResult<&str, u8> {
     verify(map_res(digit1, |s: &str| s.parse::<u8>()), |&month| {
         (1..=12).contains(&month)
@@ -48,14 +115,40 @@ fn parse_month(input: &str) -> NomResult<&str, u8> {
     .parse(input)
 }
 
+/// A month name that is a whole word, so "2 decades" is not read as "2 dec".
+pub(crate) fn parse_month_name(input: &str) -> NomResult<&str, u8> {
+    map(
+        terminated(Month::parse, not(satisfy(|c| c.is_alphabetic()))),
+        |month| month.number_from_january(),
+    )
+    .parse(input)
+}
+
 fn parse_year(input: &str) -> NomResult<&str, u32> {
     map_res(digit1, |s: &str| s.parse::<u32>()).parse(input)
 }
 
+/// A year after a space that is not the start of a time such as "10:00" or "8pm".
+fn parse_trailing_year(input: &str) -> NomResult<&str, u32> {
+    preceded(
+        space1,
+        terminated(
+            parse_year,
+            not(satisfy(|c| c.is_alphanumeric() || c == ':' || c == '.')),
+        ),
+    )
+    .parse(input)
+}
+
 fn parse_with_dashes_dd_mm_yyyy(input: &str) -> NomResult<&str, WhenExactDate> {
     map(
         (parse_day, tag("-"), parse_month, tag("-"), parse_year),
-        |(day, _, month, _, year)| WhenExactDate { year, month, day },
+        |(day, _, month, _, year)| WhenExactDate {
+            weekday: None,
+            year: Some(year),
+            month,
+            day,
+        },
     )
     .parse(input)
 }
@@ -63,7 +156,12 @@ fn parse_with_dashes_dd_mm_yyyy(input: &str) -> NomResult<&str, WhenExactDate> {
 fn parse_with_slashes_dd_mm_yyyy(input: &str) -> NomResult<&str, WhenExactDate> {
     map(
         (parse_day, tag("/"), parse_month, tag("/"), parse_year),
-        |(day, _, month, _, year)| WhenExactDate { year, month, day },
+        |(day, _, month, _, year)| WhenExactDate {
+            weekday: None,
+            year: Some(year),
+            month,
+            day,
+        },
     )
     .parse(input)
 }
@@ -71,7 +169,12 @@ fn parse_with_slashes_dd_mm_yyyy(input: &str) -> NomResult<&str, WhenExactDate>
 fn parse_with_dashes_yyyy_mm_dd(input: &str) -> NomResult<&str, WhenExactDate> {
     map(
         (parse_year, tag("-"), parse_month, tag("-"), parse_day),
-        |(year, _, month, _, day)| WhenExactDate { year, month, day },
+        |(year, _, month, _, day)| WhenExactDate {
+            weekday: None,
+            year: Some(year),
+            month,
+            day,
+        },
     )
     .parse(input)
 }
@@ -79,36 +182,84 @@ fn parse_with_dashes_yyyy_mm_dd(input: &str) -> NomResult<&str, WhenExactDate> {
 fn parse_with_slashes_yyyy_mm_dd(input: &str) -> NomResult<&str, WhenExactDate> {
     map(
         (parse_year, tag("/"), parse_month, tag("/"), parse_day),
-        |(year, _, month, _, day)| WhenExactDate { year, month, day },
+        |(year, _, month, _, day)| WhenExactDate {
+            weekday: None,
+            year: Some(year),
+            month,
+            day,
+        },
+    )
+    .parse(input)
+}
+
+/// An ISO 8601 ordinal date such as `2026-291`, the 291st day of 2026.
+fn parse_ordinal_date(input: &str) -> NomResult<&str, WhenExactDate> {
+    map_res(
+        (
+            parse_year,
+            tag("-"),
+            take_while_m_n(3, 3, |c: char| c.is_ascii_digit()),
+            not(satisfy(|c| c.is_ascii_digit())),
+        ),
+        |(year, _, day_of_year, _)| {
+            let day_of_year = day_of_year.parse::<i16>().map_err(|_| {
+                jiff::Error::from_args(format_args!("invalid day of year '{day_of_year}'"))
+            })?;
+            let date = i16::try_from(year)
+                .map_err(|_| jiff::Error::from_args(format_args!("invalid year '{year}'")))
+                .and_then(|year| jiff::civil::Date::new(year, 1, 1))?
+                .with()
+                .day_of_year(day_of_year)
+                .build()?;
+
+            Ok::<_, jiff::Error>(WhenExactDate {
+                weekday: None,
+                year: Some(year),
+                month: date.month() as u8,
+                day: date.day() as u8,
+            })
+        },
     )
     .parse(input)
 }
 
+/// `March 3`, `March 3rd 2026` or `March 3, 2026`.
 fn parse_mmm_dd_yyyy(input: &str) -> NomResult<&str, WhenExactDate> {
     map(
         (
-            Month::parse,
-            space1,
-            parse_day,
-            tag(","),
+            parse_month_name,
             space1,
-            parse_year,
+            parse_ordinal_day,
+            opt(alt((
+                preceded((tag(","), space1), parse_year),
+                parse_trailing_year,
+            ))),
         ),
-        |(month, _, day, _, _, year)| WhenExactDate {
+        |(month, _, day, year)| WhenExactDate {
+            weekday: None,
             year,
-            month: month.number_from_january(),
+            month,
             day,
         },
     )
     .parse(input)
 }
 
+/// `3 March`, `3rd March 2026` or `the 3rd of March`.
 fn parse_dd_mmm_yyyy(input: &str) -> NomResult<&str, WhenExactDate> {
     map(
-        (parse_day, space1, Month::parse, space1, parse_year),
-        |(day, _, month, _, year)| WhenExactDate {
+        (
+            opt((tag("the"), space1)),
+            parse_ordinal_day,
+            space1,
+            opt((tag("of"), space1)),
+            parse_month_name,
+            opt(parse_trailing_year),
+        ),
+        |(_, day, _, _, month, year)| WhenExactDate {
+            weekday: None,
             year,
-            month: month.number_from_january(),
+            month,
             day,
         },
     )
@@ -120,7 +271,7 @@ mod tests {
     use insta::assert_debug_snapshot;
     use nom::Finish;
 
-    use crate::WhenExactDate;
+    use crate::{EvalContext, Weekday, WhenExactDate, YearBias};
 
     #[test]
     fn parse_dashes() {
@@ -130,7 +281,8 @@ mod tests {
             Ok((
                 "",
                 WhenExactDate {
-                    year: 2004,
+                    weekday: None,
+                    year: Some(2004),
                     month: 1,
                     day: 1
                 }
@@ -146,7 +298,8 @@ mod tests {
             Ok((
                 "",
                 WhenExactDate {
-                    year: 2004,
+                    weekday: None,
+                    year: Some(2004),
                     month: 1,
                     day: 1
                 }
@@ -162,7 +315,8 @@ mod tests {
             Ok((
                 "",
                 WhenExactDate {
-                    year: 2004,
+                    weekday: None,
+                    year: Some(2004),
                     month: 1,
                     day: 1
                 }
@@ -178,7 +332,8 @@ mod tests {
             Ok((
                 "",
                 WhenExactDate {
-                    year: 2004,
+                    weekday: None,
+                    year: Some(2004),
                     month: 1,
                     day: 1
                 }
@@ -186,6 +341,167 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn parse_ordinal_date() {
+        let out = WhenExactDate::parse("2026-291");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenExactDate {
+                    weekday: None,
+                    year: Some(2026),
+                    month: 10,
+                    day: 18
+                }
+            ))
+        ));
+
+        let out = WhenExactDate::parse("2024-366");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenExactDate {
+                    weekday: None,
+                    year: Some(2024),
+                    month: 12,
+                    day: 31
+                }
+            ))
+        ));
+
+        assert!(WhenExactDate::parse("2026-366").is_err());
+        assert!(WhenExactDate::parse("2026-000").is_err());
+        assert!(WhenExactDate::parse("67562-291").is_err());
+        assert!(WhenExactDate::parse("10000-001").is_err());
+    }
+
+    #[test]
+    fn parse_ordinal() {
+        let out = WhenExactDate::parse("March 3rd");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenExactDate {
+                    weekday: None,
+                    year: None,
+                    month: 3,
+                    day: 3
+                }
+            ))
+        ));
+
+        let out = WhenExactDate::parse("the 3rd of March");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenExactDate {
+                    weekday: None,
+                    year: None,
+                    month: 3,
+                    day: 3
+                }
+            ))
+        ));
+
+        let out = WhenExactDate::parse("21st march 2026");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenExactDate {
+                    weekday: None,
+                    year: Some(2026),
+                    month: 3,
+                    day: 21
+                }
+            ))
+        ));
+
+        let out = WhenExactDate::parse("March 12th, 2026");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenExactDate {
+                    weekday: None,
+                    year: Some(2026),
+                    month: 3,
+                    day: 12
+                }
+            ))
+        ));
+
+        assert!(WhenExactDate::parse("March 3th").is_err());
+        assert!(WhenExactDate::parse("11st March").is_err());
+    }
+
+    #[test]
+    fn parse_without_year() {
+        let out = WhenExactDate::parse("3 March");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenExactDate {
+                    weekday: None,
+                    year: None,
+                    month: 3,
+                    day: 3
+                }
+            ))
+        ));
+
+        let out = WhenExactDate::parse("3 March 10:00");
+        assert!(matches!(
+            out,
+            Ok((" 10:00", WhenExactDate { year: None, .. }))
+        ));
+
+        let out = WhenExactDate::parse("March 3 8pm");
+        assert!(matches!(
+            out,
+            Ok((" 8pm", WhenExactDate { year: None, .. }))
+        ));
+
+        let out = WhenExactDate::parse("2 decades");
+        assert!(out.is_err());
+    }
+
+    #[test]
+    fn parse_weekday_prefix() {
+        let out = WhenExactDate::parse("Friday, March 6, 2026");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenExactDate {
+                    weekday: Some(Weekday::Friday),
+                    year: Some(2026),
+                    month: 3,
+                    day: 6
+                }
+            ))
+        ));
+
+        let out = WhenExactDate::parse("tuesday 3 march");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenExactDate {
+                    weekday: Some(Weekday::Tuesday),
+                    year: None,
+                    month: 3,
+                    day: 3
+                }
+            ))
+        ));
+    }
+
     #[test]
     fn parse_invalid_month() {
         let input = "01/13/2004";
@@ -218,10 +534,53 @@ mod tests {
     #[test]
     fn parse_exact_date_timestamp() {
         let (_, out) = WhenExactDate::parse("01/01/2004").unwrap();
-        let timestamp = out.to_timestamp(jiff::tz::TimeZone::UTC).unwrap();
+        let timestamp = out
+            .to_timestamp(&EvalContext::default(), jiff::tz::TimeZone::UTC)
+            .unwrap();
 
         assert_eq!(timestamp.year(), 2004);
         assert_eq!(timestamp.month(), 1);
         assert_eq!(timestamp.day(), 1);
     }
+
+    #[test]
+    fn year_out_of_range() {
+        let ctx = EvalContext::default();
+
+        for input in ["10/10/70000", "10/10/10000", "March 3, 67562"] {
+            let (_, out) = WhenExactDate::parse(input).unwrap();
+            assert!(
+                out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).is_err(),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn weekday_must_match() {
+        let ctx = EvalContext::default();
+
+        let (_, out) = WhenExactDate::parse("friday, March 6, 2026").unwrap();
+        assert!(out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).is_ok());
+
+        let (_, out) = WhenExactDate::parse("thursday, March 6, 2026").unwrap();
+        assert!(out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).is_err());
+    }
+
+    #[test]
+    fn year_follows_bias() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let (_, out) = WhenExactDate::parse("March 3rd").unwrap();
+        let timestamp = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+        assert_eq!(timestamp.date(), jiff::civil::date(2025, 3, 3));
+
+        let ctx = EvalContext {
+            year_bias: YearBias::Next,
+            ..ctx
+        };
+        let timestamp = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+        assert_eq!(timestamp.date(), jiff::civil::date(2026, 3, 3));
+    }
 }
@@ -0,0 +1,167 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::space1,
+    combinator::{map, opt},
+    sequence::preceded,
+    Parser,
+};
+
+use crate::{parse_month_name, parse_period_year, EvalContext, Granularity, NomResult};
+
+/// A whole month or year, such as `March 2027` or `in 2027`.
+///
+/// A year on its own has to be introduced by `in` or `year`, so that a
+/// military time such as `1730` is not read as a year.
+#[derive(Debug)]
+pub struct WhenExactPeriod {
+    pub year: u32,
+    pub month: Option<u8>,
+}
+
+impl WhenExactPeriod {
+    pub fn parse(input: &str) -> NomResult<&str, WhenExactPeriod> {
+        alt((
+            map(
+                preceded(
+                    opt((tag("in"), space1)),
+                    (parse_month_name, space1, parse_period_year),
+                ),
+                |(month, _, year)| WhenExactPeriod {
+                    year,
+                    month: Some(month),
+                },
+            ),
+            map(
+                preceded((alt((tag("in"), tag("year"))), space1), parse_period_year),
+                |year| WhenExactPeriod { year, month: None },
+            ),
+        ))
+        .parse(input)
+    }
+
+    pub fn granularity(&self) -> Granularity {
+        match self.month {
+            Some(_) => Granularity::Month,
+            None => Granularity::Year,
+        }
+    }
+
+    /// The first instant of the period.
+    pub fn to_timestamp(
+        &self,
+        ctx: &EvalContext,
+        timezone: jiff::tz::TimeZone,
+    ) -> Result<jiff::Zoned, jiff::Error> {
+        let month = self.month.unwrap_or(1);
+        let date = jiff::civil::Date::new(self.year as i16, month as i8, 1)?;
+        ctx.start_of_day(date, timezone)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_resolves, EvalContext, Granularity, WhenExactPeriod};
+
+    #[test]
+    fn parse_month_and_year() {
+        let out = WhenExactPeriod::parse("March 2027");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenExactPeriod {
+                    year: 2027,
+                    month: Some(3)
+                }
+            ))
+        ));
+
+        let out = WhenExactPeriod::parse("in dec 2026 at noon");
+        assert!(matches!(
+            out,
+            Ok((
+                " at noon",
+                WhenExactPeriod {
+                    year: 2026,
+                    month: Some(12)
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_year() {
+        for input in ["in 2027", "year 2027"] {
+            let out = WhenExactPeriod::parse(input);
+            assert!(matches!(
+                out,
+                Ok((
+                    "",
+                    WhenExactPeriod {
+                        year: 2027,
+                        month: None
+                    }
+                ))
+            ));
+        }
+    }
+
+    #[test]
+    fn parse_not_a_year() {
+        for input in [
+            "2027-03-01",
+            "2027/03/01",
+            "2027",
+            "1730",
+            "in 2027-03-01",
+            "1730h",
+            "0830",
+            "20:30",
+            "202",
+            "20270",
+        ] {
+            assert!(WhenExactPeriod::parse(input).is_err(), "{input}");
+        }
+        assert!(WhenExactPeriod::parse("March 3").is_err());
+    }
+
+    #[test]
+    fn to_timestamp() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+        let tz = jiff::tz::TimeZone::get("Asia/Tokyo").unwrap();
+
+        let (_, out) = WhenExactPeriod::parse("March 2027").unwrap();
+        assert_eq!(out.granularity(), Granularity::Month);
+        let zoned = out.to_timestamp(&ctx, tz.clone()).unwrap();
+        assert_eq!(zoned.timestamp(), "2027-02-28T15:00:00Z".parse().unwrap());
+
+        let (_, out) = WhenExactPeriod::parse("in 2027").unwrap();
+        assert_eq!(out.granularity(), Granularity::Year);
+        let zoned = out.to_timestamp(&ctx, tz).unwrap();
+        assert_eq!(zoned.timestamp(), "2026-12-31T15:00:00Z".parse().unwrap());
+    }
+
+    #[test]
+    fn months_and_years() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let cases = [
+            ("March 2027", "2027-03-01T00:00:00Z"),
+            ("in March", "2025-03-01T00:00:00Z"),
+            ("next March", "2026-03-01T00:00:00Z"),
+            ("last December", "2024-12-01T00:00:00Z"),
+            ("in 2027", "2027-01-01T00:00:00Z"),
+            ("year 2027 in Asia/Tokyo", "2026-12-31T15:00:00Z"),
+            ("1730", "2025-07-03T17:30:00Z"),
+            ("1730 tomorrow", "2025-07-04T17:30:00Z"),
+            ("1200", "2025-07-03T12:00:00Z"),
+            ("2027", "2025-07-03T20:27:00Z"),
+            ("next March at noon", "2026-03-01T12:00:00Z"),
+        ];
+
+        assert_resolves(&ctx, &cases);
+    }
+}
@@ -1,26 +1,57 @@
-use nom::{branch::alt, combinator::map, Parser};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::satisfy,
+    combinator::{map, not, opt},
+    Parser,
+};
 
-use crate::{AmPmTime, GmtTime, NomResult};
+use crate::{AmPmTime, EvalContext, GmtTime, NomResult, SpokenTime};
 
 #[derive(Debug)]
 pub enum WhenExactTime {
+    /// `24:00`, the midnight at the end of the day.
+    EndOfDay,
+    Spoken(SpokenTime),
     AmPm(AmPmTime),
     Gmt(GmtTime),
 }
 
+fn end_of_day(input: &str) -> NomResult<&str, ()> {
+    map(
+        (
+            tag("24:00"),
+            opt(tag(":00")),
+            not(satisfy(|c| c.is_alphanumeric() || c == ':')),
+        ),
+        |_| (),
+    )
+    .parse(input)
+}
+
 impl WhenExactTime {
     pub fn parse(input: &str) -> NomResult<&str, WhenExactTime> {
         alt((
+            map(end_of_day, |_| WhenExactTime::EndOfDay),
+            map(SpokenTime::parse, WhenExactTime::Spoken),
             map(AmPmTime::parse, WhenExactTime::AmPm),
             map(GmtTime::parse, WhenExactTime::Gmt),
         ))
         .parse(input)
     }
 
-    pub fn with_zoned(&self, zoned: jiff::Zoned) -> Result<jiff::Zoned, jiff::Error> {
+    pub fn with_zoned(
+        &self,
+        ctx: &EvalContext,
+        zoned: jiff::Zoned,
+    ) -> Result<jiff::Zoned, jiff::Error> {
         match self {
-            WhenExactTime::AmPm(am_pm_time) => am_pm_time.with_zoned(zoned),
-            WhenExactTime::Gmt(gmt_time) => gmt_time.with_zoned(zoned),
+            WhenExactTime::EndOfDay => {
+                ctx.at_time(&zoned.tomorrow()?, jiff::civil::Time::midnight())
+            }
+            WhenExactTime::Spoken(spoken_time) => spoken_time.with_zoned(ctx, zoned),
+            WhenExactTime::AmPm(am_pm_time) => am_pm_time.with_zoned(ctx, zoned),
+            WhenExactTime::Gmt(gmt_time) => gmt_time.with_zoned(ctx, zoned),
         }
     }
 }
@@ -30,7 +61,7 @@ mod tests {
     use insta::assert_debug_snapshot;
     use nom::Finish;
 
-    use crate::{AmPm, AmPmTime, GmtTime, WhenExactTime};
+    use crate::{AmPm, AmPmTime, EvalContext, GmtTime, WhenExactTime};
 
     #[test]
     fn parse_am_pm() {
@@ -43,6 +74,7 @@ mod tests {
                     hour: 1,
                     minute: 0,
                     second: 0,
+                    nanosecond: 0,
                     period: AmPm::Am
                 })
             ))
@@ -60,11 +92,79 @@ mod tests {
                     hour: 1,
                     minute: 0,
                     second: 0,
+                    nanosecond: 0,
                 })
             ))
         ));
     }
 
+    #[test]
+    fn parse_end_of_day() {
+        let out = WhenExactTime::parse("24:00");
+        assert!(matches!(out, Ok(("", WhenExactTime::EndOfDay))));
+
+        let out = WhenExactTime::parse("24:00:00");
+        assert!(matches!(out, Ok(("", WhenExactTime::EndOfDay))));
+
+        assert!(WhenExactTime::parse("24:01").is_err());
+    }
+
+    #[test]
+    fn parse_spoken() {
+        let out = WhenExactTime::parse("half past 3");
+        assert!(matches!(out, Ok(("", WhenExactTime::Spoken(_)))));
+    }
+
+    #[test]
+    fn same_times() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+        let zoned = now.to_zoned(jiff::tz::TimeZone::UTC);
+
+        let groups = [
+            [
+                "8:30 PM",
+                "8:30pm",
+                "20:30",
+                "2030",
+                "2030h",
+                "half past 8 pm",
+            ],
+            ["5 PM", "5pm", "17:00", "1700", "5 o'clock pm", "17 o'clock"],
+            [
+                "8:45",
+                "0845",
+                "quarter to 9",
+                "a quarter to nine",
+                "8:45 a.m.",
+                "0845h",
+            ],
+        ];
+
+        for group in groups {
+            let times = group.map(|input| {
+                let (rest, out) = WhenExactTime::parse(input).unwrap();
+                assert_eq!(rest, "", "{input}");
+                out.with_zoned(&ctx, zoned.clone()).unwrap().time()
+            });
+            assert!(times.iter().all(|time| *time == times[0]), "{group:?}");
+        }
+    }
+
+    #[test]
+    fn end_of_day_is_next_midnight() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+        let zoned = now.to_zoned(jiff::tz::TimeZone::UTC);
+
+        let (_, out) = WhenExactTime::parse("24:00").unwrap();
+        let timestamp = out.with_zoned(&ctx, zoned).unwrap();
+        assert_eq!(
+            timestamp.timestamp(),
+            "2025-07-04T00:00:00Z".parse().unwrap()
+        );
+    }
+
     #[test]
     fn parse_unknow() {
         let input = "unknown";
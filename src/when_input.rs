@@ -2,48 +2,129 @@ use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::space1,
-    combinator::{eof, map},
+    combinator::{eof, map, opt, value},
+    multi::many0,
+    sequence::preceded,
     Parser,
 };
 
-use crate::{NomResult, WhenDate, WhenTime, WhenTimezone};
+use crate::{
+    EvalContext, NomResult, WhenAdjustment, WhenDate, WhenDuration, WhenIsoDateTime,
+    WhenRfcDateTime, WhenTime, WhenTimezone,
+};
 
 #[derive(Debug)]
 pub struct WhenInput {
     pub time: WhenInputTime,
     pub timezone: Option<WhenTimezone>,
+    /// Trailing `+`/`-` terms, applied in order once the time and timezone
+    /// are resolved.
+    pub adjustments: Vec<WhenAdjustment>,
+}
+
+fn adjustments(input: &str) -> NomResult<&str, Vec<WhenAdjustment>> {
+    many0(preceded(space1, WhenAdjustment::parse)).parse(input)
 }
 
 #[derive(Debug)]
 pub enum WhenInputTime {
     Now,
+    Iso(WhenIsoDateTime),
+    Rfc(WhenRfcDateTime),
     OnlyDate(WhenDate),
     OnlyTime(WhenTime),
-    DateAndTime { date: WhenDate, time: WhenTime },
+    DateAndTime {
+        date: WhenDate,
+        time: WhenTime,
+    },
+    /// A duration measured from another expression, as in `3 days after 2026-01-01`
+    /// or `5 hours before noon tomorrow`.
+    Anchored {
+        duration: WhenDuration,
+        direction: Direction,
+        anchor: Box<WhenInputTime>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// `after` or `from`
+    After,
+    Before,
+}
+
+fn direction(input: &str) -> NomResult<&str, Direction> {
+    alt((
+        value(Direction::After, alt((tag("after"), tag("from")))),
+        value(Direction::Before, tag("before")),
+    ))
+    .parse(input)
+}
+
+/// A time with an optional leading "at", as in "at noon".
+fn at_time(input: &str) -> NomResult<&str, WhenTime> {
+    preceded(opt((tag("at"), space1)), WhenTime::parse).parse(input)
 }
 
 impl WhenInputTime {
+    /// Dates are tried before times, so that an input such as "12 march 2026"
+    /// is read as a date rather than as noon on some other date.
     pub fn parse(input: &str) -> NomResult<&str, WhenInputTime> {
         alt((
             map(tag("now"), |_| WhenInputTime::Now),
+            map(WhenIsoDateTime::parse, WhenInputTime::Iso),
+            map(WhenRfcDateTime::parse, WhenInputTime::Rfc),
             map(
-                (WhenDate::parse, space1, tag("at"), space1, WhenTime::parse),
-                |(date, _, _, _, time)| WhenInputTime::DateAndTime { date, time },
+                (
+                    WhenDuration::parse,
+                    space1,
+                    direction,
+                    space1,
+                    WhenInputTime::parse,
+                ),
+                |(duration, _, direction, _, anchor)| WhenInputTime::Anchored {
+                    duration,
+                    direction,
+                    anchor: Box::new(anchor),
+                },
             ),
+            map((WhenDate::parse, space1, at_time), |(date, _, time)| {
+                WhenInputTime::DateAndTime { date, time }
+            }),
             map(WhenDate::parse, WhenInputTime::OnlyDate),
-            map(WhenTime::parse, WhenInputTime::OnlyTime),
+            map((at_time, space1, WhenDate::parse), |(time, _, date)| {
+                WhenInputTime::DateAndTime { date, time }
+            }),
+            map(at_time, WhenInputTime::OnlyTime),
         ))
         .parse(input)
     }
 
-    pub fn to_timestamp(&self, timezone: jiff::tz::TimeZone) -> Result<jiff::Zoned, jiff::Error> {
+    pub fn to_timestamp(
+        &self,
+        ctx: &EvalContext,
+        timezone: jiff::tz::TimeZone,
+    ) -> Result<jiff::Zoned, jiff::Error> {
         match self {
-            WhenInputTime::Now => Ok(jiff::Zoned::now().with_time_zone(timezone)),
-            WhenInputTime::OnlyDate(when_date) => when_date.to_timestamp(timezone),
-            WhenInputTime::OnlyTime(when_time) => when_time.to_timestamp(timezone),
+            WhenInputTime::Now => Ok(ctx.now_in(timezone)),
+            WhenInputTime::Iso(when_iso_datetime) => when_iso_datetime.to_timestamp(ctx, timezone),
+            WhenInputTime::Rfc(when_rfc_datetime) => when_rfc_datetime.to_timestamp(ctx, timezone),
+            WhenInputTime::OnlyDate(when_date) => when_date.to_timestamp(ctx, timezone),
+            WhenInputTime::OnlyTime(when_time) => when_time.to_timestamp(ctx, timezone),
             WhenInputTime::DateAndTime { date, time } => {
-                let zoned = date.to_timestamp(timezone)?;
-                time.with_zoned(zoned)
+                let zoned = date.to_timestamp(ctx, timezone)?;
+                time.with_zoned(ctx, zoned)
+            }
+            WhenInputTime::Anchored {
+                duration,
+                direction,
+                anchor,
+            } => {
+                let zoned = anchor.to_timestamp(ctx, timezone)?;
+                match direction {
+                    Direction::After => zoned.checked_add(duration.to_span()?),
+                    Direction::Before => zoned.checked_sub(duration.to_span()?),
+                }
             }
         }
     }
@@ -52,44 +133,66 @@ impl WhenInputTime {
 impl WhenInput {
     pub fn parse(input: &str) -> NomResult<&str, WhenInput> {
         alt((
-            map((WhenInputTime::parse, eof), |(time, _)| WhenInput {
-                time,
-                timezone: None,
-            }),
+            map(
+                (WhenInputTime::parse, adjustments, eof),
+                |(time, adjustments, _)| WhenInput {
+                    time,
+                    timezone: None,
+                    adjustments,
+                },
+            ),
             map(
                 (
                     WhenInputTime::parse,
+                    adjustments,
                     space1,
                     tag("in"),
                     space1,
                     WhenTimezone::parse,
                 ),
-                |(time, _, _, _, timezone)| WhenInput {
+                |(time, adjustments, _, _, _, timezone)| WhenInput {
                     time,
                     timezone: Some(timezone),
+                    adjustments,
                 },
             ),
         ))
         .parse(input)
     }
 
-    pub fn to_timestamp(&self) -> Result<jiff::Zoned, jiff::Error> {
+    /// Resolves the input against `ctx`, using the context's timezone when
+    /// the input does not name a timezone of its own.
+    pub fn to_timestamp(&self, ctx: &EvalContext) -> Result<jiff::Zoned, jiff::Error> {
         let timezone = match self.timezone {
             Some(ref timezone) => timezone.to_timezone()?,
-            None => jiff::tz::TimeZone::UTC,
+            None => ctx.timezone.clone(),
         };
 
-        let zoned = self.time.to_timestamp(timezone)?;
-        Ok(zoned)
+        self.adjustments.iter().try_fold(
+            self.time.to_timestamp(ctx, timezone)?,
+            |zoned, adjustment| adjustment.apply(ctx, zoned),
+        )
+    }
+}
+
+/// Resolves each input and compares it with its expected RFC 3339 timestamp,
+/// for tests that check a parser as part of a whole expression.
+#[cfg(test)]
+pub(crate) fn assert_resolves(ctx: &EvalContext, cases: &[(&str, &str)]) {
+    for (input, expected) in cases {
+        let (_, out) = WhenInput::parse(input).unwrap();
+        let zoned = out.to_timestamp(ctx).unwrap();
+        assert_eq!(zoned.timestamp(), expected.parse().unwrap(), "{input}");
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        AmPm, AmPmTime, DateDuration, TimeDuration, TimeKind, WhenDate, WhenExactTime, WhenInput,
-        WhenInputTime, WhenNamedTimezone, WhenRelativeDate, WhenRelativeTime, WhenTime,
-        WhenTimezone, WhenUtcOffset, WhenUtcOffsetSign,
+        assert_resolves, AmPm, AmPmTime, DateDuration, EvalContext, GmtTime, TimeDuration,
+        TimeKind, Weekday, WhenDate, WhenDuration, WhenExactTime, WhenInput, WhenInputTime,
+        WhenNamedTimezone, WhenRelativeDate, WhenRelativeTime, WhenTime, WhenTimezone,
+        WhenUtcOffset, WhenUtcOffsetSign,
     };
 
     #[test]
@@ -101,9 +204,10 @@ mod tests {
                 "",
                 WhenInput {
                     time: WhenInputTime::OnlyDate(WhenDate::Relative(WhenRelativeDate::In(
-                        DateDuration::Days(10)
+                        WhenDuration::Date(DateDuration::Days(10))
                     ))),
-                    timezone: None
+                    timezone: None,
+                    ..
                 }
             ))
         ));
@@ -118,9 +222,12 @@ mod tests {
                 "",
                 WhenInput {
                     time: WhenInputTime::OnlyTime(WhenTime::Relative(
-                        WhenRelativeTime::PreviousDuration(TimeDuration::Hours(10))
+                        WhenRelativeTime::PreviousDuration(WhenDuration::Time(
+                            TimeDuration::Hours(10)
+                        ))
                     )),
-                    timezone: None
+                    timezone: None,
+                    ..
                 }
             ))
         ));
@@ -135,12 +242,15 @@ mod tests {
                 "",
                 WhenInput {
                     time: WhenInputTime::DateAndTime {
-                        date: WhenDate::Relative(WhenRelativeDate::Ago(DateDuration::Days(10))),
+                        date: WhenDate::Relative(WhenRelativeDate::Ago(WhenDuration::Date(
+                            DateDuration::Days(10)
+                        ))),
                         time: WhenTime::Relative(WhenRelativeTime::NextDuration(
-                            TimeDuration::Hours(10)
+                            WhenDuration::Time(TimeDuration::Hours(10))
                         )),
                     },
                     timezone: None,
+                    ..
                 },
             ),)
         ));
@@ -159,15 +269,115 @@ mod tests {
                 "",
                 WhenInput {
                     time: WhenInputTime::DateAndTime {
-                        date: WhenDate::Relative(WhenRelativeDate::Ago(DateDuration::Days(10))),
+                        date: WhenDate::Relative(WhenRelativeDate::Ago(WhenDuration::Date(
+                            DateDuration::Days(10)
+                        ))),
                         time: WhenTime::Relative(WhenRelativeTime::PreviousKind(TimeKind::Hour)),
                     },
                     timezone: Some(WhenTimezone::Named(WhenNamedTimezone { name })),
+                    ..
                 },
             ),)
         ));
     }
 
+    #[test]
+    fn parse_time_before_date() {
+        let out = WhenInput::parse("noon next friday");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenInput {
+                    time: WhenInputTime::DateAndTime {
+                        date: WhenDate::Relative(WhenRelativeDate::NextDay(Weekday::Friday)),
+                        time: WhenTime::Relative(WhenRelativeTime::Noon),
+                    },
+                    timezone: None,
+                    ..
+                },
+            ))
+        ));
+
+        let out = WhenInput::parse("at 9 on friday in UTC");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenInput {
+                    time: WhenInputTime::DateAndTime {
+                        date: WhenDate::Relative(WhenRelativeDate::UpcomingDay(Weekday::Friday)),
+                        time: WhenTime::Exact(WhenExactTime::Gmt(GmtTime {
+                            hour: 9,
+                            minute: 0,
+                            second: 0,
+                            nanosecond: 0,
+                        })),
+                    },
+                    timezone: Some(_),
+                    ..
+                },
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_date_space_time() {
+        let out = WhenInput::parse("tomorrow 8:00 PM");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenInput {
+                    time: WhenInputTime::DateAndTime {
+                        date: WhenDate::Relative(WhenRelativeDate::Tomorrow),
+                        time: WhenTime::Exact(WhenExactTime::AmPm(AmPmTime {
+                            hour: 8,
+                            minute: 0,
+                            second: 0,
+                            nanosecond: 0,
+                            period: AmPm::Pm,
+                        })),
+                    },
+                    timezone: None,
+                    ..
+                },
+            ))
+        ));
+
+        let out = WhenInput::parse("on friday at 9");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenInput {
+                    time: WhenInputTime::DateAndTime {
+                        date: WhenDate::Relative(WhenRelativeDate::UpcomingDay(Weekday::Friday)),
+                        time: WhenTime::Exact(WhenExactTime::Gmt(GmtTime { hour: 9, .. })),
+                    },
+                    timezone: None,
+                    ..
+                },
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_exact_date_stays_a_date() {
+        let out = WhenInput::parse("12 march 2026");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenInput {
+                    time: WhenInputTime::OnlyDate(WhenDate::Exact(_)),
+                    timezone: None,
+                    ..
+                },
+            ))
+        ));
+    }
+
     #[test]
     fn parse_sth() {
         let out = WhenInput::parse("in 2 months at 8:30 PM in UTC-8");
@@ -177,11 +387,14 @@ mod tests {
                 "",
                 WhenInput {
                     time: WhenInputTime::DateAndTime {
-                        date: WhenDate::Relative(WhenRelativeDate::In(DateDuration::Months(2))),
+                        date: WhenDate::Relative(WhenRelativeDate::In(WhenDuration::Date(
+                            DateDuration::Months(2)
+                        ))),
                         time: WhenTime::Exact(WhenExactTime::AmPm(AmPmTime {
                             hour: 8,
                             minute: 30,
                             second: 0,
+                            nanosecond: 0,
                             period: AmPm::Pm,
                         })),
                     },
@@ -190,9 +403,266 @@ mod tests {
                         hour: 8,
                         minute: 0,
                         second: 0,
+                        nanosecond: 0,
                     })),
+                    ..
                 },
             ),)
         ));
     }
+
+    #[test]
+    fn parse_adjustments() {
+        let (_, out) = WhenInput::parse("tomorrow + 1 day - 2 hours").unwrap();
+        assert_eq!(out.adjustments.len(), 2);
+
+        let (_, out) = WhenInput::parse("tomorrow - 2 hours in Asia/Tokyo").unwrap();
+        assert_eq!(out.adjustments.len(), 1);
+        assert!(out.timezone.is_some());
+
+        assert!(WhenInput::parse("tomorrow + 3 business").is_err());
+    }
+
+    #[test]
+    fn now_is_the_given_timestamp() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+
+        let (_, out) = WhenInput::parse("now").unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+        let first = out.to_timestamp(&ctx).unwrap();
+        let second = out.to_timestamp(&ctx).unwrap();
+
+        assert_eq!(first.timestamp(), now);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn default_timezone_is_used_without_timezone() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let addis = jiff::tz::TimeZone::get("Africa/Addis_Ababa").unwrap();
+        let ctx = EvalContext::new(now, addis);
+
+        let (_, out) = WhenInput::parse("yesterday at noon").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-07-02T09:00:00Z".parse().unwrap());
+
+        let (_, out) = WhenInput::parse("yesterday at noon in UTC+0").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-07-02T12:00:00Z".parse().unwrap());
+    }
+
+    #[test]
+    fn time_duration_with_time_and_timezone() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let (_, out) = WhenInput::parse("2 hours ago in Asia/Tokyo").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-07-03T00:22:00Z".parse().unwrap());
+
+        // 03:22 on 2025-07-03 in Tokyo
+        let (_, out) = WhenInput::parse("in 30 minutes at noon in Asia/Tokyo").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-07-03T03:00:00Z".parse().unwrap());
+    }
+
+    #[test]
+    fn compound_durations() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let (_, out) = WhenInput::parse("1 day 3 hours 20 minutes ago").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-07-01T23:02:00Z".parse().unwrap());
+
+        let (_, out) = WhenInput::parse("in 2 weeks and 3 days").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-07-20T02:22:00Z".parse().unwrap());
+
+        let (_, out) = WhenInput::parse("1h30m ago").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-07-03T00:52:00Z".parse().unwrap());
+    }
+
+    #[test]
+    fn fractional_durations() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let (_, out) = WhenInput::parse("in 1.5 hours").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-07-03T03:52:00Z".parse().unwrap());
+
+        let (_, out) = WhenInput::parse("2.5 days ago").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-06-30T14:22:00Z".parse().unwrap());
+
+        assert!(WhenInput::parse("in 1.5 months").is_err());
+    }
+
+    #[test]
+    fn long_and_short_units() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let (_, out) = WhenInput::parse("500 ms ago").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-07-03T02:21:59.5Z".parse().unwrap());
+
+        let (_, out) = WhenInput::parse("in 250 microseconds").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(
+            zoned.timestamp(),
+            "2025-07-03T02:22:00.00025Z".parse().unwrap()
+        );
+
+        let (_, out) = WhenInput::parse("in 2 quarters").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2026-01-03T02:22:00Z".parse().unwrap());
+
+        let (_, out) = WhenInput::parse("3 decades ago").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "1995-07-03T02:22:00Z".parse().unwrap());
+
+        let (_, out) = WhenInput::parse("in a fortnight").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-07-17T02:22:00Z".parse().unwrap());
+    }
+
+    #[test]
+    fn today_and_neighbouring_days() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let (_, out) = WhenInput::parse("today at noon").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-07-03T12:00:00Z".parse().unwrap());
+
+        let (_, out) = WhenInput::parse("the day after tomorrow at 9:00 AM in Asia/Tokyo").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-07-05T00:00:00Z".parse().unwrap());
+
+        let (_, out) = WhenInput::parse("ereyesterday at midnight").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-07-01T00:00:00Z".parse().unwrap());
+    }
+
+    #[test]
+    fn word_orders_resolve_the_same() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+        let expected: jiff::Timestamp = "2025-07-04T20:00:00Z".parse().unwrap();
+
+        for input in [
+            "tomorrow at 8:00 PM",
+            "tomorrow 8:00 PM",
+            "8:00 PM tomorrow",
+            "at 20:00 tomorrow",
+            "on friday at 20:00",
+            "20:00 on friday",
+        ] {
+            let (_, out) = WhenInput::parse(input).unwrap();
+            let zoned = out.to_timestamp(&ctx).unwrap();
+            assert_eq!(zoned.timestamp(), expected, "{input}");
+        }
+
+        let (_, out) = WhenInput::parse("last monday at 22:30 in Asia/Tokyo").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-06-30T13:30:00Z".parse().unwrap());
+    }
+
+    #[test]
+    fn compact_and_spoken_times() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let (_, out) = WhenInput::parse("8pm tomorrow").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-07-04T20:00:00Z".parse().unwrap());
+
+        let (_, out) = WhenInput::parse("tomorrow at half past 3 pm in Asia/Tokyo").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-07-04T06:30:00Z".parse().unwrap());
+
+        let (_, out) = WhenInput::parse("yesterday at 24:00").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-07-03T00:00:00Z".parse().unwrap());
+
+        let (_, out) = WhenInput::parse("tomorrow at quarter to 0").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-07-03T23:45:00Z".parse().unwrap());
+
+        for input in ["half past a", "an to 9", "a couple past 3"] {
+            assert!(WhenInput::parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn fractional_seconds() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let (_, out) = WhenInput::parse("tomorrow at 12:30:45.123456789").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(
+            zoned.timestamp(),
+            "2025-07-04T12:30:45.123456789Z".parse().unwrap()
+        );
+
+        let (_, out) = WhenInput::parse("tomorrow at 12:30:45.25 pm in UTC+2").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(
+            zoned.timestamp(),
+            "2025-07-04T10:30:45.25Z".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn dates_without_a_year() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let (_, out) = WhenInput::parse("the 3rd of March at noon").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-03-03T12:00:00Z".parse().unwrap());
+
+        let (_, out) = WhenInput::parse("8pm on Friday, July 4th").unwrap();
+        let zoned = out.to_timestamp(&ctx).unwrap();
+        assert_eq!(zoned.timestamp(), "2025-07-04T20:00:00Z".parse().unwrap());
+
+        let (_, out) = WhenInput::parse("Monday, July 4th").unwrap();
+        assert!(out.to_timestamp(&ctx).is_err());
+    }
+
+    #[test]
+    fn anchored_offsets() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let cases = [
+            ("3 days after 2026-01-01", "2026-01-04T00:00:00Z"),
+            ("2 weeks before next friday", "2025-06-20T02:22:00Z"),
+            ("a week from tomorrow", "2025-07-11T02:22:00Z"),
+            ("friday after next", "2025-07-11T02:22:00Z"),
+            ("5 hours after noon tomorrow", "2025-07-04T17:00:00Z"),
+            ("90 minutes before tomorrow at 9:00", "2025-07-04T07:30:00Z"),
+            (
+                "2 days after 3 days after 2026-01-01",
+                "2026-01-06T00:00:00Z",
+            ),
+            (
+                "1 day after 2026-03-29 in Europe/Berlin",
+                "2026-03-29T22:00:00Z",
+            ),
+            ("a week from now", "2025-07-10T02:22:00Z"),
+        ];
+
+        assert_resolves(&ctx, &cases);
+
+        let (_, out) = WhenInput::parse("the day after tomorrow").unwrap();
+        assert!(matches!(
+            out.time,
+            WhenInputTime::OnlyDate(WhenDate::Relative(WhenRelativeDate::DayAfterTomorrow))
+        ));
+    }
 }
@@ -1,9 +1,83 @@
-use nom::Finish;
+use nom::{combinator::all_consuming, Finish, Parser};
 use pgrx::{pg_sys::panic::ErrorReportable, prelude::*};
 
 ::pgrx::pg_module_magic!();
 
-use crate::WhenInput;
+use crate::{
+    EvalContext, NamedTimes, NowSource, WhenInput, WhenTimezone, DEFAULT_TIMEZONE,
+    FISCAL_YEAR_START, NAMED_TIMES, NOW_SOURCE, YEAR_BIAS,
+};
+
+/// Microseconds between the Unix epoch and the PostgreSQL epoch (2000-01-01 00:00:00 UTC).
+const POSTGRES_EPOCH_OFFSET_MICROS: i64 = 946_684_800_000_000;
+
+#[pg_guard]
+pub extern "C-unwind" fn _PG_init() {
+    crate::guc::init();
+}
+
+fn to_jiff_timestamp(timestamp: pgrx::datum::TimestampWithTimeZone) -> jiff::Timestamp {
+    if !timestamp.is_finite() {
+        error!("reference timestamp must be finite")
+    }
+
+    let micros = pg_sys::TimestampTz::from(timestamp) + POSTGRES_EPOCH_OFFSET_MICROS;
+    jiff::Timestamp::from_microsecond(micros).unwrap_or_report()
+}
+
+fn now() -> jiff::Timestamp {
+    let now = match NOW_SOURCE.get() {
+        NowSource::Transaction => pgrx::datum::transaction_timestamp(),
+        NowSource::Statement => pgrx::datum::statement_timestamp(),
+        NowSource::Clock => pgrx::datum::clock_timestamp(),
+    };
+
+    to_jiff_timestamp(now)
+}
+
+fn session_timezone() -> jiff::tz::TimeZone {
+    let name = unsafe { pg_sys::GetConfigOption(c"TimeZone".as_ptr(), false, false) };
+    let name = unsafe { std::ffi::CStr::from_ptr(name) }.to_string_lossy();
+
+    // the TimeZone setting is either an IANA name or a POSIX string like "<+03>-03"
+    jiff::tz::TimeZone::get(&name)
+        .or_else(|_| jiff::tz::TimeZone::posix(&name))
+        .unwrap_or_report()
+}
+
+fn default_timezone() -> jiff::tz::TimeZone {
+    match DEFAULT_TIMEZONE.get() {
+        Some(name) if !name.to_bytes().eq_ignore_ascii_case(b"session") => {
+            parse_timezone(&name.to_string_lossy())
+        }
+        _ => session_timezone(),
+    }
+}
+
+fn named_times() -> NamedTimes {
+    match NAMED_TIMES.get() {
+        Some(overrides) => NamedTimes::default()
+            .with_overrides(&overrides.to_string_lossy())
+            .unwrap_or_report(),
+        None => NamedTimes::default(),
+    }
+}
+
+/// The context of the STABLE variants, which follow the session's settings.
+fn session_context(now: jiff::Timestamp) -> EvalContext {
+    EvalContext {
+        named_times: named_times(),
+        year_bias: YEAR_BIAS.get().into(),
+        fiscal_year_start: FISCAL_YEAR_START.get() as i8,
+        ..EvalContext::new(now, default_timezone())
+    }
+}
+
+/// The context of the IMMUTABLE variants, which only depend on their arguments
+/// and so ignore the session's settings.
+fn fixed_context(now: jiff::Timestamp, default_zone: &str) -> EvalContext {
+    EvalContext::new(now, parse_timezone(default_zone))
+}
 
 fn parse_input(input: &str) -> WhenInput {
     let result = WhenInput::parse(input).finish();
@@ -16,52 +90,158 @@ fn parse_input(input: &str) -> WhenInput {
     }
 }
 
-#[pg_extern(strict, immutable, parallel_safe)]
-fn when_is(input: &str) -> pgrx::datum::TimestampWithTimeZone {
+fn parse_timezone(timezone: &str) -> jiff::tz::TimeZone {
+    let result = all_consuming(WhenTimezone::parse).parse(timezone).finish();
+
+    match result {
+        Ok((_, timezone)) => timezone.to_timezone().unwrap_or_report(),
+        Err(_) => {
+            error!("parsing timezone '{timezone}' failed")
+        }
+    }
+}
+
+fn evaluate(input: &str, ctx: &EvalContext) -> jiff::Zoned {
     let input = parse_input(input);
+    input.to_timestamp(ctx).unwrap_or_report()
+}
 
-    let zoned = input.to_timestamp().unwrap_or_report();
-    let zoned = zoned.with_time_zone(jiff::tz::TimeZone::UTC);
+fn to_timestamptz(zoned: jiff::Zoned) -> pgrx::datum::TimestampWithTimeZone {
+    // timestamptz has microsecond precision. Truncate rather than round, so
+    // the last nanosecond of a period such as "end of the year" stays in it.
+    let timestamp = zoned
+        .timestamp()
+        .round(
+            jiff::TimestampRound::new()
+                .smallest(jiff::Unit::Microsecond)
+                .mode(jiff::RoundMode::Floor),
+        )
+        .unwrap_or_report();
 
-    pgrx::datum::TimestampWithTimeZone::with_timezone(
-        zoned.year() as i32,
-        zoned.month() as u8,
-        zoned.day() as u8,
-        zoned.hour() as u8,
-        zoned.minute() as u8,
-        zoned.second() as f64,
-        "UTC",
-    )
-    .unwrap_or_report()
+    let micros = timestamp.as_microsecond() - POSTGRES_EPOCH_OFFSET_MICROS;
+    pgrx::datum::TimestampWithTimeZone::try_from(micros).unwrap_or_report()
 }
 
-#[pg_extern(strict, immutable, parallel_safe)]
+fn to_nanos(zoned: jiff::Zoned) -> i64 {
+    match zoned.timestamp().as_nanosecond().try_into() {
+        Ok(nanos) => nanos,
+        Err(_) => error!("nanosecond can not be represented as a bigint"),
+    }
+}
+
+#[pg_extern(strict, stable, parallel_safe)]
+fn when_is(input: &str) -> pgrx::datum::TimestampWithTimeZone {
+    to_timestamptz(evaluate(input, &session_context(now())))
+}
+
+#[pg_extern(name = "when_is", strict, stable, parallel_safe)]
+fn when_is_from(
+    input: &str,
+    reference: pgrx::datum::TimestampWithTimeZone,
+) -> pgrx::datum::TimestampWithTimeZone {
+    let reference = to_jiff_timestamp(reference);
+    to_timestamptz(evaluate(input, &session_context(reference)))
+}
+
+#[pg_extern(name = "when_is", strict, immutable, parallel_safe)]
+fn when_is_from_in(
+    input: &str,
+    reference: pgrx::datum::TimestampWithTimeZone,
+    default_zone: &str,
+) -> pgrx::datum::TimestampWithTimeZone {
+    let reference = to_jiff_timestamp(reference);
+    to_timestamptz(evaluate(input, &fixed_context(reference, default_zone)))
+}
+
+#[pg_extern(strict, stable, parallel_safe)]
 fn seconds_at(input: &str) -> i64 {
-    let input = parse_input(input);
-    let zoned = input.to_timestamp().unwrap_or_report();
+    let zoned = evaluate(input, &session_context(now()));
     zoned.timestamp().as_second()
 }
 
-#[pg_extern(strict, immutable, parallel_safe)]
+#[pg_extern(name = "seconds_at", strict, stable, parallel_safe)]
+fn seconds_at_from(input: &str, reference: pgrx::datum::TimestampWithTimeZone) -> i64 {
+    let reference = to_jiff_timestamp(reference);
+    let zoned = evaluate(input, &session_context(reference));
+    zoned.timestamp().as_second()
+}
+
+#[pg_extern(name = "seconds_at", strict, immutable, parallel_safe)]
+fn seconds_at_from_in(
+    input: &str,
+    reference: pgrx::datum::TimestampWithTimeZone,
+    default_zone: &str,
+) -> i64 {
+    let reference = to_jiff_timestamp(reference);
+    let zoned = evaluate(input, &fixed_context(reference, default_zone));
+    zoned.timestamp().as_second()
+}
+
+#[pg_extern(strict, stable, parallel_safe)]
 fn millis_at(input: &str) -> i64 {
-    let input = parse_input(input);
-    let zoned = input.to_timestamp().unwrap_or_report();
+    let zoned = evaluate(input, &session_context(now()));
+    zoned.timestamp().as_millisecond()
+}
+
+#[pg_extern(name = "millis_at", strict, stable, parallel_safe)]
+fn millis_at_from(input: &str, reference: pgrx::datum::TimestampWithTimeZone) -> i64 {
+    let reference = to_jiff_timestamp(reference);
+    let zoned = evaluate(input, &session_context(reference));
     zoned.timestamp().as_millisecond()
 }
 
-#[pg_extern(strict, immutable, parallel_safe)]
+#[pg_extern(name = "millis_at", strict, immutable, parallel_safe)]
+fn millis_at_from_in(
+    input: &str,
+    reference: pgrx::datum::TimestampWithTimeZone,
+    default_zone: &str,
+) -> i64 {
+    let reference = to_jiff_timestamp(reference);
+    let zoned = evaluate(input, &fixed_context(reference, default_zone));
+    zoned.timestamp().as_millisecond()
+}
+
+#[pg_extern(strict, stable, parallel_safe)]
 fn micros_at(input: &str) -> i64 {
-    let input = parse_input(input);
-    let zoned = input.to_timestamp().unwrap_or_report();
+    let zoned = evaluate(input, &session_context(now()));
+    zoned.timestamp().as_microsecond()
+}
+
+#[pg_extern(name = "micros_at", strict, stable, parallel_safe)]
+fn micros_at_from(input: &str, reference: pgrx::datum::TimestampWithTimeZone) -> i64 {
+    let reference = to_jiff_timestamp(reference);
+    let zoned = evaluate(input, &session_context(reference));
+    zoned.timestamp().as_microsecond()
+}
+
+#[pg_extern(name = "micros_at", strict, immutable, parallel_safe)]
+fn micros_at_from_in(
+    input: &str,
+    reference: pgrx::datum::TimestampWithTimeZone,
+    default_zone: &str,
+) -> i64 {
+    let reference = to_jiff_timestamp(reference);
+    let zoned = evaluate(input, &fixed_context(reference, default_zone));
     zoned.timestamp().as_microsecond()
 }
 
-#[pg_extern(strict, immutable, parallel_safe)]
+#[pg_extern(strict, stable, parallel_safe)]
 fn nanos_at(input: &str) -> i64 {
-    let input = parse_input(input);
-    let zoned = input.to_timestamp().unwrap_or_report();
-    match zoned.timestamp().as_nanosecond().try_into() {
-        Ok(nanos) => nanos,
-        Err(_) => error!("nanosecond can not be represented as a bigint"),
-    }
+    to_nanos(evaluate(input, &session_context(now())))
+}
+
+#[pg_extern(name = "nanos_at", strict, stable, parallel_safe)]
+fn nanos_at_from(input: &str, reference: pgrx::datum::TimestampWithTimeZone) -> i64 {
+    let reference = to_jiff_timestamp(reference);
+    to_nanos(evaluate(input, &session_context(reference)))
+}
+
+#[pg_extern(name = "nanos_at", strict, immutable, parallel_safe)]
+fn nanos_at_from_in(
+    input: &str,
+    reference: pgrx::datum::TimestampWithTimeZone,
+    default_zone: &str,
+) -> i64 {
+    let reference = to_jiff_timestamp(reference);
+    to_nanos(evaluate(input, &fixed_context(reference, default_zone)))
 }
@@ -0,0 +1,145 @@
+use nom::{bytes::complete::take_till1, combinator::map_res, Parser};
+
+use crate::{EvalContext, NomResult};
+
+/// A machine-generated ISO 8601 datetime, as written by RFC 3339 and RFC 9557.
+#[derive(Debug)]
+pub enum WhenIsoDateTime {
+    /// A datetime with a `Z`, a UTC offset or a bracketed timezone, such as
+    /// `2026-10-18T14:30:00Z` or `2026-10-18T14:30:00-04:00[America/New_York]`.
+    Instant(jiff::Timestamp),
+    /// A datetime without an offset, such as `2026-10-18T14:30:00`, which is
+    /// read in the input's timezone.
+    Local(jiff::civil::DateTime),
+}
+
+impl WhenIsoDateTime {
+    /// Parses a single whitespace free token. Both the extended and the basic
+    /// format are accepted, but a time is required, so `2026-10-18` is left to
+    /// the exact date parser.
+    pub fn parse(input: &str) -> NomResult<&str, WhenIsoDateTime> {
+        map_res(take_till1(char::is_whitespace), |token: &str| {
+            if token.contains('[') {
+                return token
+                    .parse::<jiff::Zoned>()
+                    .map(|zoned| WhenIsoDateTime::Instant(zoned.timestamp()));
+            }
+
+            if let Ok(timestamp) = token.parse::<jiff::Timestamp>() {
+                return Ok(WhenIsoDateTime::Instant(timestamp));
+            }
+
+            if !token.contains(['T', 't']) {
+                return Err(jiff::Error::from_args(format_args!(
+                    "'{token}' is missing a time"
+                )));
+            }
+
+            token
+                .parse::<jiff::civil::DateTime>()
+                .map(WhenIsoDateTime::Local)
+        })
+        .parse(input)
+    }
+
+    pub fn to_timestamp(
+        &self,
+        ctx: &EvalContext,
+        timezone: jiff::tz::TimeZone,
+    ) -> Result<jiff::Zoned, jiff::Error> {
+        match self {
+            WhenIsoDateTime::Instant(timestamp) => Ok(timestamp.to_zoned(timezone)),
+            WhenIsoDateTime::Local(datetime) => timezone
+                .to_ambiguous_zoned(*datetime)
+                .disambiguate(ctx.disambiguation),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_resolves, EvalContext, WhenInput, WhenInputTime, WhenIsoDateTime};
+
+    #[test]
+    fn parse_instant() {
+        let cases = [
+            ("2026-10-18T14:30:00Z", "2026-10-18T14:30:00Z"),
+            ("2026-10-18t14:30:00.123z", "2026-10-18T14:30:00.123Z"),
+            ("20261018T143000Z", "2026-10-18T14:30:00Z"),
+            ("2026-10-18T14:30:00+03:00", "2026-10-18T11:30:00Z"),
+            (
+                "2026-10-18T14:30:00-04:00[America/New_York]",
+                "2026-10-18T18:30:00Z",
+            ),
+            (
+                "2026-10-18T14:30:00[America/New_York]",
+                "2026-10-18T18:30:00Z",
+            ),
+        ];
+
+        for (input, expected) in cases {
+            let out = WhenIsoDateTime::parse(input);
+            let expected: jiff::Timestamp = expected.parse().unwrap();
+            assert!(
+                matches!(out, Ok(("", WhenIsoDateTime::Instant(timestamp))) if timestamp == expected),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_local() {
+        let out = WhenIsoDateTime::parse("2026-10-18T14:30:00 in UTC");
+        assert!(matches!(
+            out,
+            Ok((" in UTC", WhenIsoDateTime::Local(datetime)))
+                if datetime == jiff::civil::datetime(2026, 10, 18, 14, 30, 0, 0)
+        ));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(WhenIsoDateTime::parse("2026-10-18").is_err());
+        assert!(WhenIsoDateTime::parse("tomorrow").is_err());
+        assert!(WhenIsoDateTime::parse("2026-10-18T14:30:00+03:00[Asia/Tokyo]").is_err());
+        assert!(WhenIsoDateTime::parse("2026-13-18T14:30:00Z").is_err());
+    }
+
+    #[test]
+    fn to_timestamp() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+        let tz = jiff::tz::TimeZone::get("Asia/Tokyo").unwrap();
+
+        let (_, out) = WhenIsoDateTime::parse("2026-10-18T14:30:00Z").unwrap();
+        let zoned = out.to_timestamp(&ctx, tz.clone()).unwrap();
+        assert_eq!(zoned.timestamp(), "2026-10-18T14:30:00Z".parse().unwrap());
+
+        let (_, out) = WhenIsoDateTime::parse("2026-10-18T14:30:00").unwrap();
+        let zoned = out.to_timestamp(&ctx, tz).unwrap();
+        assert_eq!(zoned.timestamp(), "2026-10-18T05:30:00Z".parse().unwrap());
+    }
+
+    #[test]
+    fn iso_datetimes() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let cases = [
+            ("2026-10-18T14:30:00Z", "2026-10-18T14:30:00Z"),
+            ("2026-10-18T14:30:00+03:00", "2026-10-18T11:30:00Z"),
+            (
+                "2026-10-18T14:30:00-04:00[America/New_York]",
+                "2026-10-18T18:30:00Z",
+            ),
+            ("20261018T143000Z", "2026-10-18T14:30:00Z"),
+            ("2026-10-18T14:30:00Z in Asia/Tokyo", "2026-10-18T14:30:00Z"),
+            ("2026-10-18T14:30:00 in UTC+3", "2026-10-18T11:30:00Z"),
+        ];
+
+        assert_resolves(&ctx, &cases);
+
+        let (_, out) = WhenInput::parse("2026-10-18").unwrap();
+        assert!(matches!(out.time, WhenInputTime::OnlyDate(_)));
+    }
+}
@@ -0,0 +1,245 @@
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::space1,
+    combinator::{map, opt, value},
+    sequence::preceded,
+    Parser,
+};
+
+use crate::{parse_month_name, parse_period_year, EvalContext, NomResult, Weekday};
+
+/// The month a [`WhenNthWeekday`] counts in.
+#[derive(Debug, Clone, Copy)]
+pub enum WhenMonthRef {
+    /// `the month` or `this month`
+    This,
+    Next,
+    Last,
+    /// `november` or `march 2027`, without a year placed by the context's year bias.
+    Named {
+        month: u8,
+        year: Option<u32>,
+    },
+}
+
+/// The nth weekday of a month, such as `the second tuesday of november` or
+/// `the last friday of the month`.
+#[derive(Debug)]
+pub struct WhenNthWeekday {
+    /// 1 to 5, or -1 for the last one.
+    pub nth: i8,
+    pub weekday: Weekday,
+    pub month: WhenMonthRef,
+}
+
+impl WhenMonthRef {
+    pub fn parse(input: &str) -> NomResult<&str, WhenMonthRef> {
+        alt((
+            value(
+                WhenMonthRef::This,
+                (alt((tag("the"), tag("this"))), space1, tag("month")),
+            ),
+            value(WhenMonthRef::Next, (tag("next"), space1, tag("month"))),
+            value(WhenMonthRef::Last, (tag("last"), space1, tag("month"))),
+            map(
+                (parse_month_name, opt(preceded(space1, parse_period_year))),
+                |(month, year)| WhenMonthRef::Named { month, year },
+            ),
+        ))
+        .parse(input)
+    }
+
+    /// The first day of the month as seen from `today`.
+    pub fn first_day(
+        &self,
+        ctx: &EvalContext,
+        today: jiff::civil::Date,
+    ) -> Result<jiff::civil::Date, jiff::Error> {
+        use jiff::ToSpan;
+
+        match *self {
+            WhenMonthRef::This => Ok(today.first_of_month()),
+            WhenMonthRef::Next => today.first_of_month().checked_add(1.month()),
+            WhenMonthRef::Last => today.first_of_month().checked_sub(1.month()),
+            WhenMonthRef::Named { month, year } => {
+                let year = match year {
+                    Some(year) => year as i16,
+                    None => ctx.year_bias.resolve_month(today, month as i8),
+                };
+                jiff::civil::Date::new(year, month as i8, 1)
+            }
+        }
+    }
+}
+
+fn nth(input: &str) -> NomResult<&str, i8> {
+    alt((
+        value(1, alt((tag("first"), tag("1st")))),
+        value(2, alt((tag("second"), tag("2nd")))),
+        value(3, alt((tag("third"), tag("3rd")))),
+        value(4, alt((tag("fourth"), tag("4th")))),
+        value(5, alt((tag("fifth"), tag("5th")))),
+        value(-1, tag("last")),
+    ))
+    .parse(input)
+}
+
+impl WhenNthWeekday {
+    pub fn parse(input: &str) -> NomResult<&str, WhenNthWeekday> {
+        map(
+            (
+                opt((tag("the"), space1)),
+                nth,
+                space1,
+                Weekday::parse,
+                space1,
+                alt((tag("of"), tag("in"))),
+                space1,
+                WhenMonthRef::parse,
+            ),
+            |(_, nth, _, weekday, _, _, _, month)| WhenNthWeekday {
+                nth,
+                weekday,
+                month,
+            },
+        )
+        .parse(input)
+    }
+
+    /// The first instant of the day. A month without that many of the
+    /// weekday, such as one with only four fridays, is an error.
+    pub fn to_timestamp(
+        &self,
+        ctx: &EvalContext,
+        timezone: jiff::tz::TimeZone,
+    ) -> Result<jiff::Zoned, jiff::Error> {
+        let today = ctx.now_in(timezone.clone()).date();
+        let month = self.month.first_day(ctx, today)?;
+
+        let date = month.nth_weekday_of_month(self.nth, self.weekday.to_jiff())?;
+        ctx.start_of_day(date, timezone)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_resolves, EvalContext, Weekday, WhenMonthRef, WhenNthWeekday};
+
+    #[test]
+    fn parse_named_month() {
+        let out = WhenNthWeekday::parse("the second tuesday of november");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenNthWeekday {
+                    nth: 2,
+                    weekday: Weekday::Tuesday,
+                    month: WhenMonthRef::Named {
+                        month: 11,
+                        year: None
+                    }
+                }
+            ))
+        ));
+
+        let out = WhenNthWeekday::parse("the 3rd thursday in March 2027");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenNthWeekday {
+                    nth: 3,
+                    weekday: Weekday::Thursday,
+                    month: WhenMonthRef::Named {
+                        month: 3,
+                        year: Some(2027)
+                    }
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_relative_month() {
+        let out = WhenNthWeekday::parse("the last friday of the month");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenNthWeekday {
+                    nth: -1,
+                    weekday: Weekday::Friday,
+                    month: WhenMonthRef::This
+                }
+            ))
+        ));
+
+        let out = WhenNthWeekday::parse("first monday of next month at 9");
+        assert!(matches!(
+            out,
+            Ok((
+                " at 9",
+                WhenNthWeekday {
+                    nth: 1,
+                    weekday: Weekday::Monday,
+                    month: WhenMonthRef::Next
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for input in ["last friday", "sixth monday of march", "second tuesday of"] {
+            assert!(WhenNthWeekday::parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn to_timestamp() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let cases = [
+            ("the second tuesday of november", "2025-11-11T00:00:00Z"),
+            ("the last friday of the month", "2025-07-25T00:00:00Z"),
+            ("first monday of next month", "2025-08-04T00:00:00Z"),
+            ("the last sunday of last month", "2025-06-29T00:00:00Z"),
+            ("the 3rd thursday in March 2027", "2027-03-18T00:00:00Z"),
+        ];
+
+        for (input, expected) in cases {
+            let (_, out) = WhenNthWeekday::parse(input).unwrap();
+            let zoned = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+            assert_eq!(zoned.timestamp(), expected.parse().unwrap(), "{input}");
+        }
+
+        // July 2025 only has four mondays
+        let (_, out) = WhenNthWeekday::parse("the fifth monday of the month").unwrap();
+        assert!(out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).is_err());
+    }
+
+    #[test]
+    fn nth_weekday_of_month() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let cases = [
+            (
+                "the second tuesday of november at 10:00",
+                "2025-11-11T10:00:00Z",
+            ),
+            ("the last friday of the month", "2025-07-25T00:00:00Z"),
+            (
+                "first monday of next month in Asia/Tokyo",
+                "2025-08-03T15:00:00Z",
+            ),
+            ("the 3rd thursday in March 2027", "2027-03-18T00:00:00Z"),
+            ("last friday", "2025-06-27T02:22:00Z"),
+        ];
+
+        assert_resolves(&ctx, &cases);
+    }
+}
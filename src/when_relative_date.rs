@@ -1,30 +1,119 @@
 use nom::{
-    branch::alt, bytes::complete::tag, character::complete::space1, combinator::map,
-    sequence::separated_pair, Parser,
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{satisfy, space1},
+    combinator::{map, not, opt},
+    sequence::{preceded, separated_pair, terminated},
+    Parser,
 };
 
-use crate::{DateDuration, DateKind, NomResult, Weekday};
+use crate::{
+    parse_period_year, DateKind, EvalContext, Granularity, Month, NomResult, Weekday, WhenDuration,
+};
 
 #[derive(Debug)]
 pub enum WhenRelativeDate {
+    Today,
     Yesterday,
     Tomorrow,
+    DayBeforeYesterday,
+    DayAfterTomorrow,
 
     LastDay(Weekday),
     NextDay(Weekday),
     ThisDay(Weekday),
+    /// A bare weekday such as `friday`, the first such day from today on.
+    UpcomingDay(Weekday),
+    /// `friday after next`, the week after `next friday`.
+    DayAfterNext(Weekday),
 
     LastKind(DateKind),
     NextKind(DateKind),
     ThisKind(DateKind),
 
-    Ago(DateDuration),
-    In(DateDuration),
+    /// `last december`, the most recent december before the current month.
+    LastMonth(Month),
+    /// `next march`, the first march after the current month.
+    NextMonth(Month),
+    /// `in march`, placed in a year according to the context's year bias.
+    InMonth(Month),
+
+    Ago(WhenDuration),
+    In(WhenDuration),
+}
+
+/// Parses "the day `relation` `day`", with the leading "the" being optional.
+fn day_relative_to<'a>(
+    relation: &'static str,
+    day: &'static str,
+) -> impl Parser<&'a str, Output = (), Error = nom::error::Error<&'a str>> {
+    map(
+        (
+            opt((tag("the"), space1)),
+            tag("day"),
+            space1,
+            tag(relation),
+            space1,
+            tag(day),
+        ),
+        |_| (),
+    )
+}
+
+/// A month name on its own, so "march 2027" is left to the exact period.
+fn month_name(input: &str) -> NomResult<&str, Month> {
+    terminated(
+        Month::parse,
+        (
+            not(satisfy(|c| c.is_alphabetic())),
+            not(preceded(space1, parse_period_year)),
+        ),
+    )
+    .parse(input)
+}
+
+/// Days from `now` until the first `weekday` after today.
+fn days_until_next(now: &jiff::Zoned, weekday: &Weekday) -> i8 {
+    let today_weekday = now.weekday().to_monday_one_offset();
+    let target_weekday = weekday.number_from_monday();
+
+    if target_weekday > today_weekday {
+        target_weekday - today_weekday
+    } else {
+        7 - (today_weekday - target_weekday)
+    }
+}
+
+/// The first instant of the period of `kind` that is `periods` away from the
+/// one containing `now`.
+fn start_of_kind(
+    ctx: &EvalContext,
+    now: &jiff::Zoned,
+    kind: &DateKind,
+    periods: i32,
+) -> Result<jiff::Zoned, jiff::Error> {
+    let start = kind.start(ctx, now.date(), periods)?;
+    ctx.start_of_day(start, now.time_zone().clone())
 }
 
 impl WhenRelativeDate {
     pub fn parse(input: &str) -> NomResult<&str, WhenRelativeDate> {
         alt((
+            map(
+                alt((
+                    map(tag("ereyesterday"), |_| ()),
+                    day_relative_to("before", "yesterday"),
+                )),
+                |_| WhenRelativeDate::DayBeforeYesterday,
+            ),
+            map(
+                alt((
+                    map(tag("overmorrow"), |_| ()),
+                    day_relative_to("after", "tomorrow"),
+                )),
+                |_| WhenRelativeDate::DayAfterTomorrow,
+            ),
+            map(tag("today"), |_| WhenRelativeDate::Today),
             map(tag("yesterday"), |_| WhenRelativeDate::Yesterday),
             map(tag("tomorrow"), |_| WhenRelativeDate::Tomorrow),
             map(
@@ -39,6 +128,11 @@ impl WhenRelativeDate {
                 separated_pair(tag("this"), space1, Weekday::parse),
                 |(_, w)| WhenRelativeDate::ThisDay(w),
             ),
+            map(
+                (Weekday::parse, space1, tag("after"), space1, tag("next")),
+                |(w, ..)| WhenRelativeDate::DayAfterNext(w),
+            ),
+            map(Weekday::parse, WhenRelativeDate::UpcomingDay),
             map(
                 separated_pair(tag("last"), space1, DateKind::parse),
                 |(_, k)| WhenRelativeDate::LastKind(k),
@@ -51,26 +145,57 @@ impl WhenRelativeDate {
                 separated_pair(tag("this"), space1, DateKind::parse),
                 |(_, k)| WhenRelativeDate::ThisKind(k),
             ),
+            map(separated_pair(tag("last"), space1, month_name), |(_, m)| {
+                WhenRelativeDate::LastMonth(m)
+            }),
+            map(separated_pair(tag("next"), space1, month_name), |(_, m)| {
+                WhenRelativeDate::NextMonth(m)
+            }),
+            map(separated_pair(tag("in"), space1, month_name), |(_, m)| {
+                WhenRelativeDate::InMonth(m)
+            }),
             map(
-                separated_pair(DateDuration::parse, space1, tag("ago")),
+                separated_pair(WhenDuration::parse, space1, tag("ago")),
                 |(d, _)| WhenRelativeDate::Ago(d),
             ),
             map(
-                separated_pair(tag("in"), space1, DateDuration::parse),
+                separated_pair(tag("in"), space1, WhenDuration::parse),
                 |(_, d)| WhenRelativeDate::In(d),
             ),
         ))
         .parse(input)
     }
 
-    pub fn to_timestamp(&self, timezone: jiff::tz::TimeZone) -> Result<jiff::Zoned, jiff::Error> {
+    pub fn granularity(&self) -> Granularity {
+        match self {
+            WhenRelativeDate::LastMonth(_)
+            | WhenRelativeDate::NextMonth(_)
+            | WhenRelativeDate::InMonth(_) => Granularity::Month,
+            WhenRelativeDate::ThisKind(kind) => kind.granularity(),
+            WhenRelativeDate::LastKind(kind) | WhenRelativeDate::NextKind(kind) => match kind {
+                DateKind::Quarter | DateKind::Half | DateKind::FiscalYear => kind.granularity(),
+                // "next month" is a month from now, not a whole month
+                DateKind::Week | DateKind::Month | DateKind::Year => Granularity::Day,
+            },
+            _ => Granularity::Day,
+        }
+    }
+
+    pub fn to_timestamp(
+        &self,
+        ctx: &EvalContext,
+        timezone: jiff::tz::TimeZone,
+    ) -> Result<jiff::Zoned, jiff::Error> {
         use jiff::ToSpan;
 
-        let now = jiff::Zoned::new(jiff::Timestamp::now(), timezone);
+        let now = ctx.now_in(timezone);
 
         match self {
+            WhenRelativeDate::Today => Ok(now),
             WhenRelativeDate::Yesterday => now.yesterday(),
             WhenRelativeDate::Tomorrow => now.tomorrow(),
+            WhenRelativeDate::DayBeforeYesterday => now.checked_sub(2.days()),
+            WhenRelativeDate::DayAfterTomorrow => now.checked_add(2.days()),
             WhenRelativeDate::LastDay(weekday) => {
                 let today_weekday = now.weekday().to_monday_one_offset();
                 let target_weekday = weekday.number_from_monday();
@@ -84,61 +209,74 @@ impl WhenRelativeDate {
                 now.checked_sub(diff_days.day())
             }
             WhenRelativeDate::NextDay(weekday) => {
-                let today_weekday = now.weekday().to_monday_one_offset();
-                let target_weekday = weekday.number_from_monday();
-
-                let diff = if target_weekday > today_weekday {
-                    target_weekday - today_weekday
-                } else {
-                    7 - (today_weekday - target_weekday)
-                };
-
-                now.checked_add(diff.days())
+                now.checked_add(days_until_next(&now, weekday).days())
+            }
+            WhenRelativeDate::DayAfterNext(weekday) => {
+                now.checked_add((days_until_next(&now, weekday) + 7).days())
             }
             WhenRelativeDate::ThisDay(weekday) => {
-                let today_weekday = now.weekday().to_monday_one_offset();
-                let target_weekday = weekday.number_from_monday();
+                let today_weekday = now.weekday().since(ctx.week_start);
+                let target_weekday = weekday.to_jiff().since(ctx.week_start);
 
                 let diff = target_weekday - today_weekday;
 
                 now.checked_add(diff.days())
             }
+            WhenRelativeDate::UpcomingDay(weekday) => {
+                let diff = weekday.to_jiff().since(now.weekday());
+                now.checked_add(diff.days())
+            }
             WhenRelativeDate::LastKind(date_kind) => match date_kind {
                 DateKind::Week => now.checked_sub(1.week()),
                 DateKind::Month => now.checked_sub(1.month()),
                 DateKind::Year => now.checked_sub(1.year()),
+                DateKind::Quarter | DateKind::Half | DateKind::FiscalYear => {
+                    start_of_kind(ctx, &now, date_kind, -1)
+                }
             },
             WhenRelativeDate::NextKind(date_kind) => match date_kind {
                 DateKind::Week => now.checked_add(1.week()),
                 DateKind::Month => now.checked_add(1.month()),
                 DateKind::Year => now.checked_add(1.year()),
-            },
-            WhenRelativeDate::ThisKind(date_kind) => match date_kind {
-                DateKind::Week => {
-                    let offset = now.weekday().to_monday_zero_offset();
-                    now.checked_sub(offset.days())
-                }
-                DateKind::Month => {
-                    let offset = now.day();
-                    now.checked_sub(offset.days())
+                DateKind::Quarter | DateKind::Half | DateKind::FiscalYear => {
+                    start_of_kind(ctx, &now, date_kind, 1)
                 }
-                DateKind::Year => {
-                    let offset = now.day_of_year();
-                    now.checked_sub(offset.days())
-                }
-            },
-            WhenRelativeDate::Ago(date_duration) => match date_duration {
-                DateDuration::Days(days) => now.checked_sub((*days as i32).days()),
-                DateDuration::Weeks(weeks) => now.checked_sub((*weeks as i32).weeks()),
-                DateDuration::Months(months) => now.checked_sub((*months as i32).months()),
-                DateDuration::Years(years) => now.checked_sub((*years as i32).years()),
-            },
-            WhenRelativeDate::In(date_duration) => match date_duration {
-                DateDuration::Days(days) => now.checked_add((*days as i32).days()),
-                DateDuration::Weeks(weeks) => now.checked_add((*weeks as i32).weeks()),
-                DateDuration::Months(months) => now.checked_add((*months as i32).months()),
-                DateDuration::Years(years) => now.checked_add((*years as i32).years()),
             },
+            WhenRelativeDate::ThisKind(date_kind) => start_of_kind(ctx, &now, date_kind, 0),
+            WhenRelativeDate::LastMonth(month) => {
+                let month = month.number_from_january() as i8;
+                let year = if month < now.month() {
+                    now.year()
+                } else {
+                    now.year() - 1
+                };
+                ctx.start_of_day(
+                    jiff::civil::Date::new(year, month, 1)?,
+                    now.time_zone().clone(),
+                )
+            }
+            WhenRelativeDate::NextMonth(month) => {
+                let month = month.number_from_january() as i8;
+                let year = if month > now.month() {
+                    now.year()
+                } else {
+                    now.year() + 1
+                };
+                ctx.start_of_day(
+                    jiff::civil::Date::new(year, month, 1)?,
+                    now.time_zone().clone(),
+                )
+            }
+            WhenRelativeDate::InMonth(month) => {
+                let month = month.number_from_january() as i8;
+                let year = ctx.year_bias.resolve_month(now.date(), month);
+                ctx.start_of_day(
+                    jiff::civil::Date::new(year, month, 1)?,
+                    now.time_zone().clone(),
+                )
+            }
+            WhenRelativeDate::Ago(duration) => now.checked_sub(duration.to_span()?),
+            WhenRelativeDate::In(duration) => now.checked_add(duration.to_span()?),
         }
     }
 }
@@ -148,7 +286,49 @@ mod tests {
     use insta::assert_debug_snapshot;
     use nom::Finish;
 
-    use crate::{DateDuration, DateKind, Weekday, WhenRelativeDate};
+    use crate::{
+        DateDuration, DateKind, EvalContext, Granularity, Month, TimeDuration, Weekday,
+        WhenDuration, WhenRelativeDate, YearBias,
+    };
+
+    #[test]
+    fn parse_today() {
+        let out = WhenRelativeDate::parse("today");
+        assert!(matches!(out, Ok(("", WhenRelativeDate::Today))));
+    }
+
+    #[test]
+    fn parse_day_before_yesterday() {
+        let out = WhenRelativeDate::parse("the day before yesterday");
+        assert!(matches!(
+            out,
+            Ok(("", WhenRelativeDate::DayBeforeYesterday))
+        ));
+
+        let out = WhenRelativeDate::parse("day before  yesterday");
+        assert!(matches!(
+            out,
+            Ok(("", WhenRelativeDate::DayBeforeYesterday))
+        ));
+
+        let out = WhenRelativeDate::parse("ereyesterday");
+        assert!(matches!(
+            out,
+            Ok(("", WhenRelativeDate::DayBeforeYesterday))
+        ));
+    }
+
+    #[test]
+    fn parse_day_after_tomorrow() {
+        let out = WhenRelativeDate::parse("the day after tomorrow");
+        assert!(matches!(out, Ok(("", WhenRelativeDate::DayAfterTomorrow))));
+
+        let out = WhenRelativeDate::parse("day after tomorrow");
+        assert!(matches!(out, Ok(("", WhenRelativeDate::DayAfterTomorrow))));
+
+        let out = WhenRelativeDate::parse("overmorrow");
+        assert!(matches!(out, Ok(("", WhenRelativeDate::DayAfterTomorrow))));
+    }
 
     #[test]
     fn parse_yesterday() {
@@ -225,6 +405,24 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn parse_day_after_next() {
+        let out = WhenRelativeDate::parse("friday after next");
+        assert!(matches!(
+            out,
+            Ok(("", WhenRelativeDate::DayAfterNext(Weekday::Friday)))
+        ));
+    }
+
+    #[test]
+    fn parse_upcoming_weekday() {
+        let out = WhenRelativeDate::parse("friday");
+        assert!(matches!(
+            out,
+            Ok(("", WhenRelativeDate::UpcomingDay(Weekday::Friday)))
+        ));
+    }
+
     #[test]
     fn parse_last_kind() {
         let out = WhenRelativeDate::parse("last week");
@@ -293,19 +491,28 @@ mod tests {
         let out = WhenRelativeDate::parse("10 days ago");
         assert!(matches!(
             out,
-            Ok(("", WhenRelativeDate::Ago(DateDuration::Days(10))))
+            Ok((
+                "",
+                WhenRelativeDate::Ago(WhenDuration::Date(DateDuration::Days(10)))
+            ))
         ));
 
         let out = WhenRelativeDate::parse("10 weeks ago");
         assert!(matches!(
             out,
-            Ok(("", WhenRelativeDate::Ago(DateDuration::Weeks(10))))
+            Ok((
+                "",
+                WhenRelativeDate::Ago(WhenDuration::Date(DateDuration::Weeks(10)))
+            ))
         ));
 
         let out = WhenRelativeDate::parse("10 months ago");
         assert!(matches!(
             out,
-            Ok(("", WhenRelativeDate::Ago(DateDuration::Months(10))))
+            Ok((
+                "",
+                WhenRelativeDate::Ago(WhenDuration::Date(DateDuration::Months(10)))
+            ))
         ));
     }
 
@@ -314,22 +521,224 @@ mod tests {
         let out = WhenRelativeDate::parse("in 10 days");
         assert!(matches!(
             out,
-            Ok(("", WhenRelativeDate::In(DateDuration::Days(10))))
+            Ok((
+                "",
+                WhenRelativeDate::In(WhenDuration::Date(DateDuration::Days(10)))
+            ))
         ));
 
         let out = WhenRelativeDate::parse("in 10 weeks");
         assert!(matches!(
             out,
-            Ok(("", WhenRelativeDate::In(DateDuration::Weeks(10))))
+            Ok((
+                "",
+                WhenRelativeDate::In(WhenDuration::Date(DateDuration::Weeks(10)))
+            ))
         ));
 
         let out = WhenRelativeDate::parse("in 10 months");
         assert!(matches!(
             out,
-            Ok(("", WhenRelativeDate::In(DateDuration::Months(10))))
+            Ok((
+                "",
+                WhenRelativeDate::In(WhenDuration::Date(DateDuration::Months(10)))
+            ))
         ));
     }
 
+    #[test]
+    fn parse_time_duration() {
+        let out = WhenRelativeDate::parse("2 hours ago");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenRelativeDate::Ago(WhenDuration::Time(TimeDuration::Hours(2)))
+            ))
+        ));
+
+        let out = WhenRelativeDate::parse("in 30 minutes");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenRelativeDate::In(WhenDuration::Time(TimeDuration::Minutes(30)))
+            ))
+        ));
+
+        let out = WhenRelativeDate::parse("45 seconds ago");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenRelativeDate::Ago(WhenDuration::Time(TimeDuration::Seconds(45)))
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_words() {
+        let out = WhenRelativeDate::parse("a week ago");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenRelativeDate::Ago(WhenDuration::Date(DateDuration::Weeks(1)))
+            ))
+        ));
+
+        let out = WhenRelativeDate::parse("in two days");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenRelativeDate::In(WhenDuration::Date(DateDuration::Days(2)))
+            ))
+        ));
+
+        let out = WhenRelativeDate::parse("an hour ago");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenRelativeDate::Ago(WhenDuration::Time(TimeDuration::Hours(1)))
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_named_month() {
+        let out = WhenRelativeDate::parse("next March");
+        assert!(matches!(
+            out,
+            Ok(("", WhenRelativeDate::NextMonth(Month::March)))
+        ));
+
+        let out = WhenRelativeDate::parse("last december");
+        assert!(matches!(
+            out,
+            Ok(("", WhenRelativeDate::LastMonth(Month::December)))
+        ));
+
+        let out = WhenRelativeDate::parse("in may at noon");
+        assert!(matches!(
+            out,
+            Ok((" at noon", WhenRelativeDate::InMonth(Month::May)))
+        ));
+
+        assert!(WhenRelativeDate::parse("in march 2027").is_err());
+        assert!(WhenRelativeDate::parse("next decade").is_err());
+    }
+
+    #[test]
+    fn named_month_to_timestamp() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let cases = [
+            ("next march", "2026-03-01T00:00:00Z"),
+            ("next december", "2025-12-01T00:00:00Z"),
+            ("next july", "2026-07-01T00:00:00Z"),
+            ("last december", "2024-12-01T00:00:00Z"),
+            ("last march", "2025-03-01T00:00:00Z"),
+            ("last july", "2024-07-01T00:00:00Z"),
+            ("in march", "2025-03-01T00:00:00Z"),
+            ("in july", "2025-07-01T00:00:00Z"),
+        ];
+
+        for (input, expected) in cases {
+            let (_, out) = WhenRelativeDate::parse(input).unwrap();
+            assert_eq!(out.granularity(), Granularity::Month);
+            let zoned = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+            assert_eq!(zoned.timestamp(), expected.parse().unwrap(), "{input}");
+        }
+
+        let ctx = EvalContext {
+            year_bias: YearBias::Next,
+            ..ctx
+        };
+        let (_, out) = WhenRelativeDate::parse("in march").unwrap();
+        let zoned = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+        assert_eq!(zoned.timestamp(), "2026-03-01T00:00:00Z".parse().unwrap());
+    }
+
+    #[test]
+    fn this_kind_to_timestamp() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let cases = [
+            ("this week", "2025-06-30T00:00:00Z", Granularity::Week),
+            ("this month", "2025-07-01T00:00:00Z", Granularity::Month),
+            ("this year", "2025-01-01T00:00:00Z", Granularity::Year),
+        ];
+
+        for (input, expected, granularity) in cases {
+            let (_, out) = WhenRelativeDate::parse(input).unwrap();
+            assert_eq!(out.granularity(), granularity, "{input}");
+            let zoned = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+            assert_eq!(zoned.timestamp(), expected.parse().unwrap(), "{input}");
+        }
+    }
+
+    #[test]
+    fn parse_fiscal_year() {
+        let out = WhenRelativeDate::parse("this fiscal year");
+        assert!(matches!(
+            out,
+            Ok(("", WhenRelativeDate::ThisKind(DateKind::FiscalYear)))
+        ));
+
+        let out = WhenRelativeDate::parse("next fiscal year");
+        assert!(matches!(
+            out,
+            Ok(("", WhenRelativeDate::NextKind(DateKind::FiscalYear)))
+        ));
+    }
+
+    #[test]
+    fn reporting_periods_to_timestamp() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let cases = [
+            ("this quarter", "2025-07-01T00:00:00Z", Granularity::Quarter),
+            ("next quarter", "2025-10-01T00:00:00Z", Granularity::Quarter),
+            ("last quarter", "2025-04-01T00:00:00Z", Granularity::Quarter),
+            ("this half", "2025-07-01T00:00:00Z", Granularity::Half),
+            ("last half", "2025-01-01T00:00:00Z", Granularity::Half),
+            ("next half", "2026-01-01T00:00:00Z", Granularity::Half),
+            (
+                "this fiscal year",
+                "2025-01-01T00:00:00Z",
+                Granularity::Year,
+            ),
+        ];
+
+        for (input, expected, granularity) in cases {
+            let (_, out) = WhenRelativeDate::parse(input).unwrap();
+            assert_eq!(out.granularity(), granularity, "{input}");
+            let zoned = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+            assert_eq!(zoned.timestamp(), expected.parse().unwrap(), "{input}");
+        }
+
+        let ctx = EvalContext {
+            fiscal_year_start: 10,
+            ..ctx
+        };
+        let cases = [
+            ("this fiscal year", "2024-10-01T00:00:00Z"),
+            ("next fiscal year", "2025-10-01T00:00:00Z"),
+            ("last fiscal year", "2023-10-01T00:00:00Z"),
+        ];
+
+        for (input, expected) in cases {
+            let (_, out) = WhenRelativeDate::parse(input).unwrap();
+            let zoned = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+            assert_eq!(zoned.timestamp(), expected.parse().unwrap(), "{input}");
+        }
+    }
+
     #[test]
     fn parse_unknown() {
         let input = "unknown";
@@ -340,7 +749,76 @@ mod tests {
     #[test]
     fn parse_yesterday_timestamp() {
         let (_, out) = WhenRelativeDate::parse("yesterday").unwrap();
-        let timestamp = out.to_timestamp(jiff::tz::TimeZone::UTC);
+        let timestamp = out.to_timestamp(&EvalContext::default(), jiff::tz::TimeZone::UTC);
         assert!(timestamp.is_ok());
     }
+
+    #[test]
+    fn parse_tomorrow_timestamp_from_now() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+
+        let (_, out) = WhenRelativeDate::parse("tomorrow").unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+        let timestamp = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+
+        assert_eq!(
+            timestamp.timestamp(),
+            "2025-07-04T02:22:00Z".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_day_offsets_timestamp() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let (_, out) = WhenRelativeDate::parse("today").unwrap();
+        let timestamp = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+        assert_eq!(timestamp.timestamp(), now);
+
+        let (_, out) = WhenRelativeDate::parse("the day after tomorrow").unwrap();
+        let timestamp = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+        assert_eq!(timestamp.date(), jiff::civil::date(2025, 7, 5));
+
+        let (_, out) = WhenRelativeDate::parse("the day before yesterday").unwrap();
+        let timestamp = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+        assert_eq!(timestamp.date(), jiff::civil::date(2025, 7, 1));
+    }
+
+    #[test]
+    fn parse_upcoming_weekday_timestamp() {
+        // a thursday
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let (_, out) = WhenRelativeDate::parse("friday").unwrap();
+        let timestamp = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+        assert_eq!(timestamp.date(), jiff::civil::date(2025, 7, 4));
+
+        let (_, out) = WhenRelativeDate::parse("thursday").unwrap();
+        let timestamp = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+        assert_eq!(timestamp.date(), jiff::civil::date(2025, 7, 3));
+
+        let (_, out) = WhenRelativeDate::parse("wednesday").unwrap();
+        let timestamp = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+        assert_eq!(timestamp.date(), jiff::civil::date(2025, 7, 9));
+    }
+
+    #[test]
+    fn parse_this_week_with_week_start() {
+        // a thursday
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let (_, out) = WhenRelativeDate::parse("this week").unwrap();
+
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+        let timestamp = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+        assert_eq!(timestamp.date(), jiff::civil::date(2025, 6, 30));
+
+        let ctx = EvalContext {
+            week_start: jiff::civil::Weekday::Sunday,
+            ..ctx
+        };
+        let timestamp = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+        assert_eq!(timestamp.date(), jiff::civil::date(2025, 6, 29));
+    }
 }
@@ -1,32 +1,80 @@
 use nom::{
-    branch::alt, bytes::complete::tag, character::complete::space1, combinator::map,
-    sequence::separated_pair, Parser,
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::space1,
+    combinator::{map, value},
+    sequence::separated_pair,
+    Parser,
 };
 
-use crate::{NomResult, TimeDuration, TimeKind};
+use crate::{EvalContext, NomResult, TimeKind, WhenDuration};
 
 #[derive(Debug)]
 pub enum WhenRelativeTime {
     Noon,
     Morning,
+    Afternoon,
     Evening,
+    Night,
+    Tonight,
     Midnight,
+    Dawn,
+    Dusk,
+    Lunchtime,
+    EndOfDay,
+    CloseOfBusiness,
+    StartOfBusiness,
 
     NextKind(TimeKind),
     PreviousKind(TimeKind),
     ThisKind(TimeKind),
 
-    NextDuration(TimeDuration),
-    PreviousDuration(TimeDuration),
+    NextDuration(WhenDuration),
+    PreviousDuration(WhenDuration),
+}
+
+/// Parses the words of a multi word name such as "close of business".
+fn words<'a>(
+    first: &'static str,
+    second: &'static str,
+    third: &'static str,
+) -> impl Parser<&'a str, Output = (), Error = nom::error::Error<&'a str>> {
+    value((), (tag(first), space1, tag(second), space1, tag(third)))
+}
+
+/// Parses the named times of day, whose clock times come from the
+/// context's [`crate::NamedTimes`].
+fn named(input: &str) -> NomResult<&str, WhenRelativeTime> {
+    alt((
+        map(tag("noon"), |_| WhenRelativeTime::Noon),
+        map(tag("morning"), |_| WhenRelativeTime::Morning),
+        map(tag("afternoon"), |_| WhenRelativeTime::Afternoon),
+        map(tag("evening"), |_| WhenRelativeTime::Evening),
+        map(tag("night"), |_| WhenRelativeTime::Night),
+        map(tag("tonight"), |_| WhenRelativeTime::Tonight),
+        map(tag("midnight"), |_| WhenRelativeTime::Midnight),
+        map(tag("dawn"), |_| WhenRelativeTime::Dawn),
+        map(tag("dusk"), |_| WhenRelativeTime::Dusk),
+        map(tag("lunchtime"), |_| WhenRelativeTime::Lunchtime),
+        map(
+            alt((words("end", "of", "day"), value((), tag("eod")))),
+            |_| WhenRelativeTime::EndOfDay,
+        ),
+        map(
+            alt((words("close", "of", "business"), value((), tag("cob")))),
+            |_| WhenRelativeTime::CloseOfBusiness,
+        ),
+        map(words("start", "of", "business"), |_| {
+            WhenRelativeTime::StartOfBusiness
+        }),
+    ))
+    .parse(input)
 }
 
 impl WhenRelativeTime {
     pub fn parse(input: &str) -> NomResult<&str, WhenRelativeTime> {
         alt((
-            map(tag("noon"), |_| WhenRelativeTime::Noon),
-            map(tag("morning"), |_| WhenRelativeTime::Morning),
-            map(tag("evening"), |_| WhenRelativeTime::Evening),
-            map(tag("midnight"), |_| WhenRelativeTime::Midnight),
+            named,
             map(
                 separated_pair(
                     alt((
@@ -62,7 +110,7 @@ impl WhenRelativeTime {
                         map(separated_pair(tag("the"), space1, tag("next")), |_| "next"),
                     )),
                     space1,
-                    TimeDuration::parse,
+                    WhenDuration::parse,
                 ),
                 |(_, w)| WhenRelativeTime::NextDuration(w),
             ),
@@ -75,7 +123,7 @@ impl WhenRelativeTime {
                         }),
                     )),
                     space1,
-                    TimeDuration::parse,
+                    WhenDuration::parse,
                 ),
                 |(_, w)| WhenRelativeTime::PreviousDuration(w),
             ),
@@ -83,13 +131,31 @@ impl WhenRelativeTime {
         .parse(input)
     }
 
-    pub fn with_zoned(&self, zoned: jiff::Zoned) -> Result<jiff::Zoned, jiff::Error> {
+    pub fn with_zoned(
+        &self,
+        ctx: &EvalContext,
+        zoned: jiff::Zoned,
+    ) -> Result<jiff::Zoned, jiff::Error> {
         use jiff::ToSpan;
         match self {
-            WhenRelativeTime::Noon => zoned.with().time(jiff::civil::time(12, 0, 0, 0)).build(),
-            WhenRelativeTime::Morning => zoned.with().time(jiff::civil::time(9, 0, 0, 0)).build(),
-            WhenRelativeTime::Evening => zoned.with().time(jiff::civil::time(18, 0, 0, 0)).build(),
-            WhenRelativeTime::Midnight => zoned.with().time(jiff::civil::time(0, 0, 0, 0)).build(),
+            WhenRelativeTime::Noon => ctx.at_time(&zoned, ctx.named_times.noon),
+            WhenRelativeTime::Morning => ctx.at_time(&zoned, ctx.named_times.morning),
+            WhenRelativeTime::Afternoon => ctx.at_time(&zoned, ctx.named_times.afternoon),
+            WhenRelativeTime::Evening => ctx.at_time(&zoned, ctx.named_times.evening),
+            WhenRelativeTime::Night | WhenRelativeTime::Tonight => {
+                ctx.at_time(&zoned, ctx.named_times.night)
+            }
+            WhenRelativeTime::Midnight => ctx.at_time(&zoned, ctx.named_times.midnight),
+            WhenRelativeTime::Dawn => ctx.at_time(&zoned, ctx.named_times.dawn),
+            WhenRelativeTime::Dusk => ctx.at_time(&zoned, ctx.named_times.dusk),
+            WhenRelativeTime::Lunchtime => ctx.at_time(&zoned, ctx.named_times.lunchtime),
+            WhenRelativeTime::EndOfDay => ctx.at_time(&zoned, ctx.named_times.end_of_day),
+            WhenRelativeTime::CloseOfBusiness => {
+                ctx.at_time(&zoned, ctx.named_times.close_of_business)
+            }
+            WhenRelativeTime::StartOfBusiness => {
+                ctx.at_time(&zoned, ctx.named_times.start_of_business)
+            }
             WhenRelativeTime::NextKind(time_kind) => match time_kind {
                 TimeKind::Hour => zoned.checked_add(1.hour()),
                 TimeKind::Minute => zoned.checked_add(1.minute()),
@@ -101,7 +167,7 @@ impl WhenRelativeTime {
                 TimeKind::Second => zoned.checked_sub(1.second()),
             },
             WhenRelativeTime::ThisKind(time_kind) => {
-                let now = jiff::Zoned::new(jiff::Timestamp::now(), zoned.time_zone().to_owned());
+                let now = ctx.now_in(zoned.time_zone().to_owned());
 
                 match time_kind {
                     TimeKind::Hour => zoned
@@ -130,16 +196,8 @@ impl WhenRelativeTime {
                         .build(),
                 }
             }
-            WhenRelativeTime::NextDuration(time_duration) => match time_duration {
-                TimeDuration::Seconds(secs) => zoned.checked_add((*secs as i32).seconds()),
-                TimeDuration::Minutes(mins) => zoned.checked_add((*mins as i32).minutes()),
-                TimeDuration::Hours(hrs) => zoned.checked_add((*hrs as i32).hours()),
-            },
-            WhenRelativeTime::PreviousDuration(time_duration) => match time_duration {
-                TimeDuration::Seconds(secs) => zoned.checked_sub((*secs as i32).seconds()),
-                TimeDuration::Minutes(mins) => zoned.checked_sub((*mins as i32).minutes()),
-                TimeDuration::Hours(hrs) => zoned.checked_sub((*hrs as i32).hours()),
-            },
+            WhenRelativeTime::NextDuration(duration) => zoned.checked_add(duration.to_span()?),
+            WhenRelativeTime::PreviousDuration(duration) => zoned.checked_sub(duration.to_span()?),
         }
     }
 }
@@ -149,7 +207,7 @@ mod tests {
     use insta::assert_debug_snapshot;
     use nom::Finish;
 
-    use crate::{TimeDuration, TimeKind, WhenRelativeTime};
+    use crate::{EvalContext, NamedTimes, TimeDuration, TimeKind, WhenDuration, WhenRelativeTime};
 
     #[test]
     fn parse_noon() {
@@ -175,6 +233,66 @@ mod tests {
         assert!(matches!(out, Ok(("", WhenRelativeTime::Midnight))));
     }
 
+    #[test]
+    fn parse_more_named_times() {
+        let out = WhenRelativeTime::parse("afternoon");
+        assert!(matches!(out, Ok(("", WhenRelativeTime::Afternoon))));
+
+        let out = WhenRelativeTime::parse("night");
+        assert!(matches!(out, Ok(("", WhenRelativeTime::Night))));
+
+        let out = WhenRelativeTime::parse("tonight");
+        assert!(matches!(out, Ok(("", WhenRelativeTime::Tonight))));
+
+        let out = WhenRelativeTime::parse("dawn");
+        assert!(matches!(out, Ok(("", WhenRelativeTime::Dawn))));
+
+        let out = WhenRelativeTime::parse("dusk");
+        assert!(matches!(out, Ok(("", WhenRelativeTime::Dusk))));
+
+        let out = WhenRelativeTime::parse("lunchtime");
+        assert!(matches!(out, Ok(("", WhenRelativeTime::Lunchtime))));
+    }
+
+    #[test]
+    fn parse_business_times() {
+        let out = WhenRelativeTime::parse("end of day");
+        assert!(matches!(out, Ok(("", WhenRelativeTime::EndOfDay))));
+
+        let out = WhenRelativeTime::parse("eod");
+        assert!(matches!(out, Ok(("", WhenRelativeTime::EndOfDay))));
+
+        let out = WhenRelativeTime::parse("close  of business");
+        assert!(matches!(out, Ok(("", WhenRelativeTime::CloseOfBusiness))));
+
+        let out = WhenRelativeTime::parse("cob");
+        assert!(matches!(out, Ok(("", WhenRelativeTime::CloseOfBusiness))));
+
+        let out = WhenRelativeTime::parse("start of business");
+        assert!(matches!(out, Ok(("", WhenRelativeTime::StartOfBusiness))));
+    }
+
+    #[test]
+    fn named_times_follow_context() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let zoned = now.to_zoned(jiff::tz::TimeZone::UTC);
+
+        let mut ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+        let (_, out) = WhenRelativeTime::parse("morning").unwrap();
+        let timestamp = out.with_zoned(&ctx, zoned.clone()).unwrap();
+        assert_eq!(timestamp.time(), jiff::civil::time(9, 0, 0, 0));
+
+        ctx.named_times = NamedTimes::default()
+            .with_overrides("morning=08:00")
+            .unwrap();
+        let timestamp = out.with_zoned(&ctx, zoned.clone()).unwrap();
+        assert_eq!(timestamp.time(), jiff::civil::time(8, 0, 0, 0));
+
+        let (_, out) = WhenRelativeTime::parse("eod").unwrap();
+        let timestamp = out.with_zoned(&ctx, zoned).unwrap();
+        assert_eq!(timestamp.time(), jiff::civil::Time::MAX);
+    }
+
     #[test]
     fn parse_next_kind() {
         let out = WhenRelativeTime::parse("next hour");
@@ -279,13 +397,19 @@ mod tests {
         let out = WhenRelativeTime::parse("next 10 hours");
         assert!(matches!(
             out,
-            Ok(("", WhenRelativeTime::NextDuration(TimeDuration::Hours(10))))
+            Ok((
+                "",
+                WhenRelativeTime::NextDuration(WhenDuration::Time(TimeDuration::Hours(10)))
+            ))
         ));
 
         let out = WhenRelativeTime::parse("the next 10 hours");
         assert!(matches!(
             out,
-            Ok(("", WhenRelativeTime::NextDuration(TimeDuration::Hours(10))))
+            Ok((
+                "",
+                WhenRelativeTime::NextDuration(WhenDuration::Time(TimeDuration::Hours(10)))
+            ))
         ));
 
         let out = WhenRelativeTime::parse("next 10 minutes");
@@ -293,7 +417,7 @@ mod tests {
             out,
             Ok((
                 "",
-                WhenRelativeTime::NextDuration(TimeDuration::Minutes(10))
+                WhenRelativeTime::NextDuration(WhenDuration::Time(TimeDuration::Minutes(10)))
             ))
         ));
 
@@ -302,7 +426,7 @@ mod tests {
             out,
             Ok((
                 "",
-                WhenRelativeTime::NextDuration(TimeDuration::Minutes(10))
+                WhenRelativeTime::NextDuration(WhenDuration::Time(TimeDuration::Minutes(10)))
             ))
         ));
 
@@ -311,7 +435,7 @@ mod tests {
             out,
             Ok((
                 "",
-                WhenRelativeTime::NextDuration(TimeDuration::Seconds(10))
+                WhenRelativeTime::NextDuration(WhenDuration::Time(TimeDuration::Seconds(10)))
             ))
         ));
 
@@ -320,7 +444,7 @@ mod tests {
             out,
             Ok((
                 "",
-                WhenRelativeTime::NextDuration(TimeDuration::Seconds(10))
+                WhenRelativeTime::NextDuration(WhenDuration::Time(TimeDuration::Seconds(10)))
             ))
         ));
     }
@@ -332,7 +456,7 @@ mod tests {
             out,
             Ok((
                 "",
-                WhenRelativeTime::PreviousDuration(TimeDuration::Hours(10))
+                WhenRelativeTime::PreviousDuration(WhenDuration::Time(TimeDuration::Hours(10)))
             ))
         ));
 
@@ -341,7 +465,7 @@ mod tests {
             out,
             Ok((
                 "",
-                WhenRelativeTime::PreviousDuration(TimeDuration::Hours(10))
+                WhenRelativeTime::PreviousDuration(WhenDuration::Time(TimeDuration::Hours(10)))
             ))
         ));
 
@@ -350,7 +474,7 @@ mod tests {
             out,
             Ok((
                 "",
-                WhenRelativeTime::PreviousDuration(TimeDuration::Minutes(10))
+                WhenRelativeTime::PreviousDuration(WhenDuration::Time(TimeDuration::Minutes(10)))
             ))
         ));
 
@@ -359,7 +483,7 @@ mod tests {
             out,
             Ok((
                 "",
-                WhenRelativeTime::PreviousDuration(TimeDuration::Minutes(10))
+                WhenRelativeTime::PreviousDuration(WhenDuration::Time(TimeDuration::Minutes(10)))
             ))
         ));
 
@@ -368,7 +492,7 @@ mod tests {
             out,
             Ok((
                 "",
-                WhenRelativeTime::PreviousDuration(TimeDuration::Seconds(10))
+                WhenRelativeTime::PreviousDuration(WhenDuration::Time(TimeDuration::Seconds(10)))
             ))
         ));
 
@@ -377,7 +501,7 @@ mod tests {
             out,
             Ok((
                 "",
-                WhenRelativeTime::PreviousDuration(TimeDuration::Seconds(10))
+                WhenRelativeTime::PreviousDuration(WhenDuration::Time(TimeDuration::Seconds(10)))
             ))
         ));
     }
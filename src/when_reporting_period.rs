@@ -0,0 +1,234 @@
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::{satisfy, space0, space1},
+    combinator::{map, map_res, not, opt, verify},
+    sequence::{preceded, terminated},
+    Parser,
+};
+
+use crate::{parse_period_year, parse_two_digit_year, EvalContext, Granularity, NomResult};
+
+/// A calendar quarter or half, or a fiscal year, such as `Q3 2026`, `H1 2027`
+/// or `FY2027`.
+#[derive(Debug)]
+pub enum WhenReportingPeriod {
+    /// `Q3 2026`, or `Q3` of the current year.
+    Quarter { quarter: u8, year: Option<u32> },
+    /// `H1 2027`, or `H1` of the current year.
+    Half { half: u8, year: Option<u32> },
+    /// `FY2027` or `FY27`, the fiscal year that ends in 2027.
+    FiscalYear { year: u32 },
+}
+
+fn number(input: &str) -> NomResult<&str, u8> {
+    map_res(
+        take_while_m_n(1, 1, |c: char| c.is_ascii_digit()),
+        |s: &str| s.parse::<u8>(),
+    )
+    .parse(input)
+}
+
+fn optional_year(input: &str) -> NomResult<&str, Option<u32>> {
+    terminated(
+        opt(preceded(space1, parse_period_year)),
+        not(satisfy(|c| c.is_alphanumeric())),
+    )
+    .parse(input)
+}
+
+fn fiscal_year(input: &str) -> NomResult<&str, u32> {
+    terminated(
+        alt((parse_period_year, parse_two_digit_year)),
+        not(satisfy(|c| c.is_alphanumeric())),
+    )
+    .parse(input)
+}
+
+impl WhenReportingPeriod {
+    pub fn parse(input: &str) -> NomResult<&str, WhenReportingPeriod> {
+        alt((
+            map(
+                (
+                    alt((tag("Q"), tag("q"))),
+                    verify(number, |quarter| (1..=4).contains(quarter)),
+                    optional_year,
+                ),
+                |(_, quarter, year)| WhenReportingPeriod::Quarter { quarter, year },
+            ),
+            map(
+                (
+                    alt((tag("H"), tag("h"))),
+                    verify(number, |half| (1..=2).contains(half)),
+                    optional_year,
+                ),
+                |(_, half, year)| WhenReportingPeriod::Half { half, year },
+            ),
+            map(
+                preceded((alt((tag("FY"), tag("fy"))), space0), fiscal_year),
+                |year| WhenReportingPeriod::FiscalYear { year },
+            ),
+        ))
+        .parse(input)
+    }
+
+    pub fn granularity(&self) -> Granularity {
+        match self {
+            WhenReportingPeriod::Quarter { .. } => Granularity::Quarter,
+            WhenReportingPeriod::Half { .. } => Granularity::Half,
+            WhenReportingPeriod::FiscalYear { .. } => Granularity::Year,
+        }
+    }
+
+    /// The first instant of the period. A fiscal year that does not start in
+    /// January starts in the year before the one it is named after.
+    pub fn to_timestamp(
+        &self,
+        ctx: &EvalContext,
+        timezone: jiff::tz::TimeZone,
+    ) -> Result<jiff::Zoned, jiff::Error> {
+        let current_year = || ctx.now_in(timezone.clone()).year();
+
+        let date = match *self {
+            WhenReportingPeriod::Quarter { quarter, year } => {
+                let year = year.map_or_else(current_year, |year| year as i16);
+                jiff::civil::Date::new(year, (quarter as i8 - 1) * 3 + 1, 1)?
+            }
+            WhenReportingPeriod::Half { half, year } => {
+                let year = year.map_or_else(current_year, |year| year as i16);
+                jiff::civil::Date::new(year, (half as i8 - 1) * 6 + 1, 1)?
+            }
+            WhenReportingPeriod::FiscalYear { year } => {
+                let year = match ctx.fiscal_year_start {
+                    1 => year as i16,
+                    _ => year as i16 - 1,
+                };
+                jiff::civil::Date::new(year, ctx.fiscal_year_start, 1)?
+            }
+        };
+
+        ctx.start_of_day(date, timezone)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_resolves, EvalContext, Granularity, WhenReportingPeriod};
+
+    #[test]
+    fn parse_quarter() {
+        let out = WhenReportingPeriod::parse("Q3 2026");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenReportingPeriod::Quarter {
+                    quarter: 3,
+                    year: Some(2026)
+                }
+            ))
+        ));
+
+        let out = WhenReportingPeriod::parse("q4 at noon");
+        assert!(matches!(
+            out,
+            Ok((
+                " at noon",
+                WhenReportingPeriod::Quarter {
+                    quarter: 4,
+                    year: None
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_half() {
+        let out = WhenReportingPeriod::parse("H1 2027");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenReportingPeriod::Half {
+                    half: 1,
+                    year: Some(2027)
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_fiscal_year() {
+        for input in ["FY2027", "FY 2027", "fy27"] {
+            let out = WhenReportingPeriod::parse(input);
+            assert!(
+                matches!(
+                    out,
+                    Ok(("", WhenReportingPeriod::FiscalYear { year: 2027 }))
+                ),
+                "{input}"
+            );
+        }
+
+        let out = WhenReportingPeriod::parse("FY99");
+        assert!(matches!(
+            out,
+            Ok(("", WhenReportingPeriod::FiscalYear { year: 1999 }))
+        ));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for input in ["Q5 2026", "Q0", "H3 2027", "Q32026", "FY202", "Qx", "hour"] {
+            assert!(WhenReportingPeriod::parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn to_timestamp() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let cases = [
+            ("Q3 2026", "2026-07-01T00:00:00Z", Granularity::Quarter),
+            ("Q1", "2025-01-01T00:00:00Z", Granularity::Quarter),
+            ("H2 2027", "2027-07-01T00:00:00Z", Granularity::Half),
+            ("FY2027", "2027-01-01T00:00:00Z", Granularity::Year),
+        ];
+
+        for (input, expected, granularity) in cases {
+            let (_, out) = WhenReportingPeriod::parse(input).unwrap();
+            assert_eq!(out.granularity(), granularity, "{input}");
+            let zoned = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+            assert_eq!(zoned.timestamp(), expected.parse().unwrap(), "{input}");
+        }
+
+        let ctx = EvalContext {
+            fiscal_year_start: 10,
+            ..ctx
+        };
+        let (_, out) = WhenReportingPeriod::parse("FY2027").unwrap();
+        let zoned = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+        assert_eq!(zoned.timestamp(), "2026-10-01T00:00:00Z".parse().unwrap());
+    }
+
+    #[test]
+    fn quarters_halves_and_fiscal_years() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext {
+            fiscal_year_start: 4,
+            ..EvalContext::new(now, jiff::tz::TimeZone::UTC)
+        };
+
+        let cases = [
+            ("Q3 2026", "2026-07-01T00:00:00Z"),
+            ("next quarter at 9:00", "2025-10-01T09:00:00Z"),
+            ("H1 2027 in Asia/Tokyo", "2026-12-31T15:00:00Z"),
+            ("FY2027", "2026-04-01T00:00:00Z"),
+            ("start of fiscal year", "2025-04-01T00:00:00Z"),
+            ("end of fiscal year", "2026-03-31T23:59:59.999999999Z"),
+        ];
+
+        assert_resolves(&ctx, &cases);
+    }
+}
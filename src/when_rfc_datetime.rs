@@ -0,0 +1,332 @@
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::{space0, space1},
+    combinator::{map, map_res, opt, value, verify},
+    sequence::{preceded, terminated},
+    Parser,
+};
+
+use crate::{parse_24, parse_60, parse_two_digit_year, EvalContext, Month, NomResult, Weekday};
+
+/// A datetime as written in email and HTTP headers: RFC 2822
+/// (`Tue, 1 Jul 2003 10:52:37 +0200`), RFC 850
+/// (`Sunday, 06-Nov-94 08:49:37 GMT`) or asctime (`Sun Nov  6 08:49:37 1994`).
+#[derive(Debug)]
+pub struct WhenRfcDateTime {
+    pub weekday: Option<Weekday>,
+    pub datetime: jiff::civil::DateTime,
+    pub offset: jiff::tz::Offset,
+}
+
+impl WhenRfcDateTime {
+    pub fn parse(input: &str) -> NomResult<&str, WhenRfcDateTime> {
+        alt((parse_rfc2822, parse_rfc850, parse_asctime)).parse(input)
+    }
+
+    /// A weekday that does not match the date is an error.
+    pub fn to_timestamp(
+        &self,
+        _ctx: &EvalContext,
+        timezone: jiff::tz::TimeZone,
+    ) -> Result<jiff::Zoned, jiff::Error> {
+        if let Some(ref weekday) = self.weekday {
+            if self.datetime.weekday() != weekday.to_jiff() {
+                return Err(jiff::Error::from_args(format_args!(
+                    "{} is a {:?}, not a {:?}",
+                    self.datetime.date(),
+                    self.datetime.weekday(),
+                    weekday.to_jiff()
+                )));
+            }
+        }
+
+        let timestamp = self.offset.to_timestamp(self.datetime)?;
+        Ok(timestamp.to_zoned(timezone))
+    }
+}
+
+/// A full weekday name or its three letter abbreviation, such as `Tue`.
+fn day_name(input: &str) -> NomResult<&str, Weekday> {
+    alt((
+        Weekday::parse,
+        map(alt((tag("Mon"), tag("mon"))), |_| Weekday::Monday),
+        map(alt((tag("Tue"), tag("tue"))), |_| Weekday::Tuesday),
+        map(alt((tag("Wed"), tag("wed"))), |_| Weekday::Wednesday),
+        map(alt((tag("Thu"), tag("thu"))), |_| Weekday::Thursday),
+        map(alt((tag("Fri"), tag("fri"))), |_| Weekday::Friday),
+        map(alt((tag("Sat"), tag("sat"))), |_| Weekday::Saturday),
+        map(alt((tag("Sun"), tag("sun"))), |_| Weekday::Sunday),
+    ))
+    .parse(input)
+}
+
+fn digits(min: usize, max: usize) -> impl Fn(&str) -> NomResult<&str, u32> {
+    move |input: &str| {
+        map_res(
+            take_while_m_n(min, max, |c: char| c.is_ascii_digit()),
+            |s: &str| s.parse::<u32>(),
+        )
+        .parse(input)
+    }
+}
+
+fn day(input: &str) -> NomResult<&str, u8> {
+    map(verify(digits(1, 2), |day| (1..=31).contains(day)), |day| {
+        day as u8
+    })
+    .parse(input)
+}
+
+/// A four digit year, or an obsolete two digit one.
+fn year(input: &str) -> NomResult<&str, i16> {
+    alt((
+        map(digits(4, 4), |year| year as i16),
+        map(parse_two_digit_year, |year| year as i16),
+    ))
+    .parse(input)
+}
+
+fn time(input: &str) -> NomResult<&str, jiff::civil::Time> {
+    map_res(
+        (
+            parse_24,
+            tag(":"),
+            parse_60,
+            opt(preceded(tag(":"), parse_60)),
+        ),
+        |(hour, _, minute, second)| {
+            jiff::civil::Time::new(hour as i8, minute as i8, second.unwrap_or(0) as i8, 0)
+        },
+    )
+    .parse(input)
+}
+
+fn numeric_offset(input: &str) -> NomResult<&str, jiff::tz::Offset> {
+    map_res(
+        (
+            alt((value(1, tag("+")), value(-1, tag("-")))),
+            digits(2, 2),
+            digits(2, 2),
+        ),
+        |(sign, hours, minutes): (i32, u32, u32)| {
+            jiff::tz::Offset::from_seconds(sign * (hours as i32 * 3600 + minutes as i32 * 60))
+        },
+    )
+    .parse(input)
+}
+
+fn gmt(input: &str) -> NomResult<&str, jiff::tz::Offset> {
+    value(jiff::tz::Offset::UTC, tag("GMT")).parse(input)
+}
+
+/// The numeric and named zones of RFC 2822, including the obsolete North
+/// American ones.
+fn zone(input: &str) -> NomResult<&str, jiff::tz::Offset> {
+    alt((
+        numeric_offset,
+        gmt,
+        value(
+            jiff::tz::Offset::UTC,
+            alt((tag("UTC"), tag("UT"), tag("Z"))),
+        ),
+        value(jiff::tz::offset(-4), tag("EDT")),
+        value(jiff::tz::offset(-5), alt((tag("EST"), tag("CDT")))),
+        value(jiff::tz::offset(-6), alt((tag("CST"), tag("MDT")))),
+        value(jiff::tz::offset(-7), alt((tag("MST"), tag("PDT")))),
+        value(jiff::tz::offset(-8), tag("PST")),
+    ))
+    .parse(input)
+}
+
+fn datetime(
+    year: i16,
+    month: Month,
+    day: u8,
+    time: jiff::civil::Time,
+) -> Result<jiff::civil::DateTime, jiff::Error> {
+    let date = jiff::civil::Date::new(year, month.number_from_january() as i8, day as i8)?;
+    Ok(date.to_datetime(time))
+}
+
+/// `[Tue, ]1 Jul 2003 10:52[:37] +0200`
+fn parse_rfc2822(input: &str) -> NomResult<&str, WhenRfcDateTime> {
+    map_res(
+        (
+            opt(terminated(day_name, (tag(","), space0))),
+            day,
+            space1,
+            Month::parse,
+            space1,
+            year,
+            space1,
+            time,
+            space1,
+            zone,
+        ),
+        |(weekday, day, _, month, _, year, _, time, _, offset)| {
+            Ok::<_, jiff::Error>(WhenRfcDateTime {
+                weekday,
+                datetime: datetime(year, month, day, time)?,
+                offset,
+            })
+        },
+    )
+    .parse(input)
+}
+
+/// `Sunday, 06-Nov-94 08:49:37 GMT`
+fn parse_rfc850(input: &str) -> NomResult<&str, WhenRfcDateTime> {
+    map_res(
+        (
+            day_name,
+            tag(","),
+            space1,
+            day,
+            tag("-"),
+            Month::parse,
+            tag("-"),
+            year,
+            space1,
+            time,
+            space1,
+            gmt,
+        ),
+        |(weekday, _, _, day, _, month, _, year, _, time, _, offset)| {
+            Ok::<_, jiff::Error>(WhenRfcDateTime {
+                weekday: Some(weekday),
+                datetime: datetime(year, month, day, time)?,
+                offset,
+            })
+        },
+    )
+    .parse(input)
+}
+
+/// `Sun Nov  6 08:49:37 1994`, which is always in UTC.
+fn parse_asctime(input: &str) -> NomResult<&str, WhenRfcDateTime> {
+    map_res(
+        (
+            day_name,
+            space1,
+            Month::parse,
+            space1,
+            day,
+            space1,
+            time,
+            space1,
+            map(digits(4, 4), |year| year as i16),
+        ),
+        |(weekday, _, month, _, day, _, time, _, year)| {
+            Ok::<_, jiff::Error>(WhenRfcDateTime {
+                weekday: Some(weekday),
+                datetime: datetime(year, month, day, time)?,
+                offset: jiff::tz::Offset::UTC,
+            })
+        },
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_resolves, EvalContext, Weekday, WhenRfcDateTime};
+
+    #[test]
+    fn parse_rfc2822() {
+        let out = WhenRfcDateTime::parse("Tue, 1 Jul 2003 10:52:37 +0200");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenRfcDateTime {
+                    weekday: Some(Weekday::Tuesday),
+                    datetime,
+                    offset,
+                }
+            )) if datetime == jiff::civil::datetime(2003, 7, 1, 10, 52, 37, 0)
+                && offset == jiff::tz::offset(2)
+        ));
+
+        let out = WhenRfcDateTime::parse("1 Jul 03 10:52 EST");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenRfcDateTime {
+                    weekday: None,
+                    datetime,
+                    offset,
+                }
+            )) if datetime == jiff::civil::datetime(2003, 7, 1, 10, 52, 0, 0)
+                && offset == jiff::tz::offset(-5)
+        ));
+    }
+
+    #[test]
+    fn parse_http_dates() {
+        let expected = jiff::civil::datetime(1994, 11, 6, 8, 49, 37, 0);
+
+        for input in [
+            "Sun, 06 Nov 1994 08:49:37 GMT",
+            "Sunday, 06-Nov-94 08:49:37 GMT",
+            "Sun Nov  6 08:49:37 1994",
+        ] {
+            let out = WhenRfcDateTime::parse(input);
+            assert!(
+                matches!(
+                    out,
+                    Ok((
+                        "",
+                        WhenRfcDateTime {
+                            weekday: Some(Weekday::Sunday),
+                            datetime,
+                            offset: jiff::tz::Offset::UTC,
+                        }
+                    )) if datetime == expected
+                ),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(WhenRfcDateTime::parse("Tue, 31 Feb 2003 10:52:37 +0200").is_err());
+        assert!(WhenRfcDateTime::parse("Tue, 1 Jul 2003 25:52:37 +0200").is_err());
+        assert!(WhenRfcDateTime::parse("1 Jul 2003 10:52:37").is_err());
+        assert!(WhenRfcDateTime::parse("tomorrow").is_err());
+    }
+
+    #[test]
+    fn to_timestamp() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let (_, out) = WhenRfcDateTime::parse("Tue, 1 Jul 2003 10:52:37 +0200").unwrap();
+        let zoned = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+        assert_eq!(zoned.timestamp(), "2003-07-01T08:52:37Z".parse().unwrap());
+
+        let (_, out) = WhenRfcDateTime::parse("Wed, 1 Jul 2003 10:52:37 +0200").unwrap();
+        assert!(out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).is_err());
+    }
+
+    #[test]
+    fn email_and_http_dates() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let cases = [
+            ("Tue, 1 Jul 2003 10:52:37 +0200", "2003-07-01T08:52:37Z"),
+            ("Sun, 06 Nov 1994 08:49:37 GMT", "1994-11-06T08:49:37Z"),
+            ("Sunday, 06-Nov-94 08:49:37 GMT", "1994-11-06T08:49:37Z"),
+            ("Sun Nov  6 08:49:37 1994", "1994-11-06T08:49:37Z"),
+            (
+                "Sun, 06 Nov 1994 08:49:37 GMT in Asia/Tokyo",
+                "1994-11-06T08:49:37Z",
+            ),
+        ];
+
+        assert_resolves(&ctx, &cases);
+    }
+}
@@ -1,6 +1,6 @@
 use nom::{branch::alt, combinator::map, Parser};
 
-use crate::{NomResult, WhenExactTime, WhenRelativeTime};
+use crate::{EvalContext, NomResult, WhenExactTime, WhenRelativeTime};
 
 #[derive(Debug)]
 pub enum WhenTime {
@@ -17,22 +17,30 @@ impl WhenTime {
         .parse(input)
     }
 
-    pub fn to_timestamp(&self, timezone: jiff::tz::TimeZone) -> Result<jiff::Zoned, jiff::Error> {
-        let now = jiff::Zoned::new(jiff::Timestamp::now(), timezone);
-        self.with_zoned(now)
+    pub fn to_timestamp(
+        &self,
+        ctx: &EvalContext,
+        timezone: jiff::tz::TimeZone,
+    ) -> Result<jiff::Zoned, jiff::Error> {
+        let zoned = ctx.now_in(timezone);
+        self.with_zoned(ctx, zoned)
     }
 
-    pub fn with_zoned(&self, zoned: jiff::Zoned) -> Result<jiff::Zoned, jiff::Error> {
+    pub fn with_zoned(
+        &self,
+        ctx: &EvalContext,
+        zoned: jiff::Zoned,
+    ) -> Result<jiff::Zoned, jiff::Error> {
         match self {
-            WhenTime::Relative(when_relative_time) => when_relative_time.with_zoned(zoned),
-            WhenTime::Exact(when_exact_time) => when_exact_time.with_zoned(zoned),
+            WhenTime::Relative(when_relative_time) => when_relative_time.with_zoned(ctx, zoned),
+            WhenTime::Exact(when_exact_time) => when_exact_time.with_zoned(ctx, zoned),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{GmtTime, TimeDuration, WhenExactTime, WhenRelativeTime, WhenTime};
+    use crate::{GmtTime, TimeDuration, WhenDuration, WhenExactTime, WhenRelativeTime, WhenTime};
 
     #[test]
     fn parse_relative() {
@@ -41,8 +49,8 @@ mod tests {
             out,
             Ok((
                 "",
-                WhenTime::Relative(WhenRelativeTime::PreviousDuration(TimeDuration::Minutes(
-                    10
+                WhenTime::Relative(WhenRelativeTime::PreviousDuration(WhenDuration::Time(
+                    TimeDuration::Minutes(10)
                 )))
             ))
         ));
@@ -58,7 +66,8 @@ mod tests {
                 WhenTime::Exact(WhenExactTime::Gmt(GmtTime {
                     hour: 10,
                     minute: 1,
-                    second: 30
+                    second: 30,
+                    nanosecond: 0
                 }))
             ))
         ));
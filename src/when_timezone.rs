@@ -44,7 +44,8 @@ mod tests {
                     sign: WhenUtcOffsetSign::Plus,
                     hour: 3,
                     minute: 0,
-                    second: 0
+                    second: 0,
+                    nanosecond: 0
                 })
             ))
         ));
@@ -16,6 +16,7 @@ pub struct WhenUtcOffset {
     pub hour: u8,
     pub minute: u8,
     pub second: u8,
+    pub nanosecond: u32,
 }
 
 #[derive(Debug)]
@@ -36,6 +37,12 @@ impl WhenUtcOffset {
     }
 
     pub fn to_timezone(&self) -> Result<jiff::tz::TimeZone, jiff::Error> {
+        if self.nanosecond != 0 {
+            return Err(jiff::Error::from_args(format_args!(
+                "UTC offsets can not have fractional seconds"
+            )));
+        }
+
         let offset = jiff::tz::Offset::from_seconds(self.to_seconds())?;
         Ok(jiff::tz::TimeZone::fixed(offset))
     }
@@ -57,11 +64,12 @@ impl WhenUtcOffset {
     pub fn parse(input: &str) -> NomResult<&str, WhenUtcOffset> {
         all_consuming(map(
             (utc, sign, parse_hms(HmsFormat::H24)),
-            |(_, sign, (hour, minute, second))| WhenUtcOffset {
+            |(_, sign, (hour, minute, second, nanosecond))| WhenUtcOffset {
                 sign,
                 hour,
                 minute,
                 second,
+                nanosecond,
             },
         ))
         .parse(input)
@@ -86,7 +94,8 @@ mod test {
                     sign: WhenUtcOffsetSign::Plus,
                     hour: 1,
                     minute: 0,
-                    second: 0
+                    second: 0,
+                    nanosecond: 0
                 }
             ))
         ));
@@ -103,7 +112,8 @@ mod test {
                     sign: WhenUtcOffsetSign::Minus,
                     hour: 1,
                     minute: 30,
-                    second: 0
+                    second: 0,
+                    nanosecond: 0
                 }
             ))
         ));
@@ -120,12 +130,38 @@ mod test {
                     sign: WhenUtcOffsetSign::Plus,
                     hour: 1,
                     minute: 30,
-                    second: 24
+                    second: 24,
+                    nanosecond: 0
                 }
             ))
         ));
     }
 
+    #[test]
+    fn parse_fractional_seconds() {
+        let out = WhenUtcOffset::parse("UTC+3:00:00.0");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenUtcOffset {
+                    sign: WhenUtcOffsetSign::Plus,
+                    hour: 3,
+                    minute: 0,
+                    second: 0,
+                    nanosecond: 0
+                }
+            ))
+        ));
+
+        let (_, offset) = WhenUtcOffset::parse("UTC+3:00:00.0").unwrap();
+        assert!(offset.to_timezone().is_ok());
+
+        let (_, offset) = WhenUtcOffset::parse("UTC+3:00:00.5").unwrap();
+        assert_eq!(offset.nanosecond, 500_000_000);
+        assert!(offset.to_timezone().is_err());
+    }
+
     #[test]
     fn parse_invalid_hour() {
         let input = "UTC+24";
@@ -0,0 +1,313 @@
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::{satisfy, space0, space1},
+    combinator::{map, map_res, not, opt, value, verify},
+    sequence::{preceded, terminated},
+    Parser,
+};
+
+use crate::{AmPm, EvalContext, Granularity, NomResult};
+
+/// A date written with ISO 8601 week numbering, where weeks start on Monday
+/// and week 1 is the week with the year's first Thursday.
+#[derive(Debug)]
+pub enum WhenWeekDate {
+    /// `2026-W42`, `2026-W42-3`, `week 42 of 2026` or `week 42`, which is in
+    /// the current week-numbering year.
+    Exact {
+        year: Option<i16>,
+        week: i8,
+        weekday: Option<i8>,
+    },
+    /// `next week 3`, a day of the week before, after or containing today.
+    Relative { weeks: i8, weekday: i8 },
+}
+
+fn number(min: usize, max: usize) -> impl Fn(&str) -> NomResult<&str, i16> {
+    move |input: &str| {
+        map_res(
+            take_while_m_n(min, max, |c: char| c.is_ascii_digit()),
+            |s: &str| s.parse::<i16>(),
+        )
+        .parse(input)
+    }
+}
+
+fn week(input: &str) -> NomResult<&str, i8> {
+    map(
+        verify(number(1, 2), |week| (1..=53).contains(week)),
+        |week| week as i8,
+    )
+    .parse(input)
+}
+
+/// An ISO weekday number from 1 (Monday) to 7 (Sunday).
+fn weekday(input: &str) -> NomResult<&str, i8> {
+    map(verify(number(1, 1), |day| (1..=7).contains(day)), |day| {
+        day as i8
+    })
+    .parse(input)
+}
+
+/// Makes sure a number is not the start of a time, as in "next week 3pm".
+fn not_a_time(input: &str) -> NomResult<&str, ()> {
+    value(
+        (),
+        (
+            not(satisfy(|c| {
+                c.is_alphanumeric() || matches!(c, ':' | '.' | '\'')
+            })),
+            not((space0, AmPm::parse)),
+            not((space0, alt((tag("o'clock"), tag("o’clock"), tag("oclock"))))),
+        ),
+    )
+    .parse(input)
+}
+
+/// `2026-W42` or `2026-W42-3`
+fn parse_iso(input: &str) -> NomResult<&str, WhenWeekDate> {
+    map(
+        (
+            number(4, 4),
+            tag("-"),
+            alt((tag("W"), tag("w"))),
+            terminated(week, not(satisfy(|c| c.is_ascii_digit()))),
+            opt(preceded(tag("-"), weekday)),
+            not(satisfy(|c| c.is_alphanumeric() || c == '-')),
+        ),
+        |(year, _, _, week, weekday, _)| WhenWeekDate::Exact {
+            year: Some(year),
+            week,
+            weekday,
+        },
+    )
+    .parse(input)
+}
+
+/// `week 42` or `week 42 of 2026`
+fn parse_words(input: &str) -> NomResult<&str, WhenWeekDate> {
+    map(
+        (
+            tag("week"),
+            space1,
+            week,
+            opt(preceded((space1, tag("of"), space1), number(4, 4))),
+            not_a_time,
+        ),
+        |(_, _, week, year, _)| WhenWeekDate::Exact {
+            year,
+            week,
+            weekday: None,
+        },
+    )
+    .parse(input)
+}
+
+/// `last week 5`, `this week 1` or `next week 3`
+fn parse_relative(input: &str) -> NomResult<&str, WhenWeekDate> {
+    map(
+        (
+            alt((
+                value(-1, tag("last")),
+                value(0, tag("this")),
+                value(1, tag("next")),
+            )),
+            space1,
+            tag("week"),
+            space1,
+            weekday,
+            not_a_time,
+        ),
+        |(weeks, _, _, _, weekday, _)| WhenWeekDate::Relative { weeks, weekday },
+    )
+    .parse(input)
+}
+
+impl WhenWeekDate {
+    pub fn parse(input: &str) -> NomResult<&str, WhenWeekDate> {
+        alt((parse_iso, parse_words, parse_relative)).parse(input)
+    }
+
+    pub fn granularity(&self) -> Granularity {
+        match self {
+            WhenWeekDate::Exact { weekday: None, .. } => Granularity::Week,
+            _ => Granularity::Day,
+        }
+    }
+
+    /// The first instant of the day, or of the Monday when no day is given.
+    pub fn to_timestamp(
+        &self,
+        ctx: &EvalContext,
+        timezone: jiff::tz::TimeZone,
+    ) -> Result<jiff::Zoned, jiff::Error> {
+        let today = ctx.now_in(timezone.clone()).date();
+
+        let date = match *self {
+            WhenWeekDate::Exact {
+                year,
+                week,
+                weekday,
+            } => {
+                let year = year.unwrap_or_else(|| today.iso_week_date().year());
+                let weekday = jiff::civil::Weekday::from_monday_one_offset(weekday.unwrap_or(1))?;
+                jiff::civil::ISOWeekDate::new(year, week, weekday)?.date()
+            }
+            WhenWeekDate::Relative { weeks, weekday } => {
+                use jiff::ToSpan;
+
+                let offset = i64::from(weeks) * 7 + i64::from(weekday)
+                    - i64::from(today.weekday().to_monday_one_offset());
+                today.checked_add(offset.days())?
+            }
+        };
+
+        ctx.start_of_day(date, timezone)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_resolves, EvalContext, Granularity, WhenWeekDate};
+
+    #[test]
+    fn parse_iso() {
+        let out = WhenWeekDate::parse("2026-W42");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenWeekDate::Exact {
+                    year: Some(2026),
+                    week: 42,
+                    weekday: None
+                }
+            ))
+        ));
+
+        let out = WhenWeekDate::parse("2026-W42-3");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenWeekDate::Exact {
+                    year: Some(2026),
+                    week: 42,
+                    weekday: Some(3)
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_words() {
+        let out = WhenWeekDate::parse("week 42 of 2026");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenWeekDate::Exact {
+                    year: Some(2026),
+                    week: 42,
+                    weekday: None
+                }
+            ))
+        ));
+
+        let out = WhenWeekDate::parse("week 42 at noon");
+        assert!(matches!(
+            out,
+            Ok((
+                " at noon",
+                WhenWeekDate::Exact {
+                    year: None,
+                    week: 42,
+                    weekday: None
+                }
+            ))
+        ));
+    }
+
+    #[test]
+    fn parse_relative() {
+        let out = WhenWeekDate::parse("next week 3");
+        assert!(matches!(
+            out,
+            Ok((
+                "",
+                WhenWeekDate::Relative {
+                    weeks: 1,
+                    weekday: 3
+                }
+            ))
+        ));
+
+        for input in [
+            "next week 3pm",
+            "next week 3 pm",
+            "next week 3:00",
+            "next week 8",
+        ] {
+            assert!(WhenWeekDate::parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for input in [
+            "2026-W54",
+            "2026-W42-8",
+            "2026-W423",
+            "week 0",
+            "2026-10-18",
+        ] {
+            assert!(WhenWeekDate::parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn to_timestamp() {
+        // a Thursday in ISO week 27 of 2025
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let cases = [
+            ("2026-W42", "2026-10-12T00:00:00Z", Granularity::Week),
+            ("2026-W42-3", "2026-10-14T00:00:00Z", Granularity::Day),
+            ("week 1 of 2026", "2025-12-29T00:00:00Z", Granularity::Week),
+            ("week 42", "2025-10-13T00:00:00Z", Granularity::Week),
+            ("next week 3", "2025-07-09T00:00:00Z", Granularity::Day),
+            ("this week 1", "2025-06-30T00:00:00Z", Granularity::Day),
+            ("last week 7", "2025-06-29T00:00:00Z", Granularity::Day),
+        ];
+
+        for (input, expected, granularity) in cases {
+            let (_, out) = WhenWeekDate::parse(input).unwrap();
+            assert_eq!(out.granularity(), granularity, "{input}");
+            let zoned = out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).unwrap();
+            assert_eq!(zoned.timestamp(), expected.parse().unwrap(), "{input}");
+        }
+
+        // 2025 only has 52 weeks
+        let (_, out) = WhenWeekDate::parse("2025-W53").unwrap();
+        assert!(out.to_timestamp(&ctx, jiff::tz::TimeZone::UTC).is_err());
+    }
+
+    #[test]
+    fn week_and_ordinal_dates() {
+        let now: jiff::Timestamp = "2025-07-03T02:22:00Z".parse().unwrap();
+        let ctx = EvalContext::new(now, jiff::tz::TimeZone::UTC);
+
+        let cases = [
+            ("2026-W42", "2026-10-12T00:00:00Z"),
+            ("2026-W42-3 at noon", "2026-10-14T12:00:00Z"),
+            ("week 42 of 2026 in Asia/Tokyo", "2026-10-11T15:00:00Z"),
+            ("next week 3", "2025-07-09T00:00:00Z"),
+            ("next week 3pm", "2025-07-10T15:00:00Z"),
+            ("2026-291", "2026-10-18T00:00:00Z"),
+        ];
+
+        assert_resolves(&ctx, &cases);
+    }
+}
@@ -0,0 +1,89 @@
+/// Which year a date written without one, such as `March 3`, falls in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YearBias {
+    /// Always the current year, even if the date has already passed.
+    Current,
+    /// The next time the date comes around, which is today or later.
+    Next,
+}
+
+impl YearBias {
+    /// Resolves `month` and `day` to a date as seen from `today`.
+    pub fn resolve(
+        &self,
+        today: jiff::civil::Date,
+        month: i8,
+        day: i8,
+    ) -> Result<jiff::civil::Date, jiff::Error> {
+        match self {
+            YearBias::Current => jiff::civil::Date::new(today.year(), month, day),
+            YearBias::Next => {
+                // February 29 may be up to 8 years away
+                for year in today.year()..=today.year().saturating_add(8) {
+                    match jiff::civil::Date::new(year, month, day) {
+                        Ok(date) if date >= today => return Ok(date),
+                        _ => continue,
+                    }
+                }
+
+                jiff::civil::Date::new(today.year(), month, day)?;
+                Err(jiff::Error::from_args(format_args!(
+                    "no upcoming date for month {month} day {day}"
+                )))
+            }
+        }
+    }
+
+    /// The year `month` falls in as seen from `today`. With `Next`, the
+    /// current month still counts as upcoming.
+    pub fn resolve_month(&self, today: jiff::civil::Date, month: i8) -> i16 {
+        match self {
+            YearBias::Next if month < today.month() => today.year() + 1,
+            _ => today.year(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::YearBias;
+
+    #[test]
+    fn resolve_current() {
+        let today = jiff::civil::date(2025, 7, 3);
+
+        let date = YearBias::Current.resolve(today, 3, 3).unwrap();
+        assert_eq!(date, jiff::civil::date(2025, 3, 3));
+
+        let date = YearBias::Current.resolve(today, 12, 25).unwrap();
+        assert_eq!(date, jiff::civil::date(2025, 12, 25));
+
+        assert!(YearBias::Current.resolve(today, 2, 29).is_err());
+    }
+
+    #[test]
+    fn resolve_next() {
+        let today = jiff::civil::date(2025, 7, 3);
+
+        let date = YearBias::Next.resolve(today, 3, 3).unwrap();
+        assert_eq!(date, jiff::civil::date(2026, 3, 3));
+
+        let date = YearBias::Next.resolve(today, 7, 3).unwrap();
+        assert_eq!(date, jiff::civil::date(2025, 7, 3));
+
+        let date = YearBias::Next.resolve(today, 2, 29).unwrap();
+        assert_eq!(date, jiff::civil::date(2028, 2, 29));
+
+        assert!(YearBias::Next.resolve(today, 2, 30).is_err());
+    }
+
+    #[test]
+    fn resolve_month() {
+        let today = jiff::civil::date(2025, 7, 3);
+
+        assert_eq!(YearBias::Current.resolve_month(today, 3), 2025);
+        assert_eq!(YearBias::Next.resolve_month(today, 3), 2026);
+        assert_eq!(YearBias::Next.resolve_month(today, 7), 2025);
+        assert_eq!(YearBias::Next.resolve_month(today, 12), 2025);
+    }
+}